@@ -1,34 +1,109 @@
+use std::collections::{BTreeSet, HashSet};
+
+mod actions;
+mod commands;
+mod compare;
+mod external;
+mod finder;
+mod focus;
+mod graveyard;
+mod help;
+mod marks;
 mod navigation;
+mod palette;
+mod pickaxe;
+mod playback;
+mod refs;
+mod report;
+mod restore;
+mod search;
+mod seismic;
+mod selection;
 mod state;
+mod trace;
+mod viewer;
 
 use git2::Repository;
 
 use crate::cli::Cli;
+use crate::config::Config;
 use crate::data::{Commit, Health, History};
 use crate::error::Result;
 use crate::git_ops;
-use crate::mouse::{HitTarget, MouseState};
-use crate::views::ViewMode;
-
-pub use navigation::scroll_timeline;
+use crate::marks::Marks;
+use crate::mouse::MouseState;
+use crate::views::{Aggregate, SortMode, ViewMode, Zoom};
+
+pub use external::ExternalOpen;
+pub use finder::Finder;
+pub use graveyard::Graveyard;
+pub use marks::MarkPrompt;
+pub use palette::Palette;
+pub use pickaxe::Pickaxe;
+pub use playback::Playback;
+pub use refs::{NewRef, RefPrompt};
+pub use report::ReportPanel;
+pub use restore::{RestorePrompt, RestoreScope};
+pub use search::CommitSearch;
 pub use state::App;
+pub use trace::LineTrace;
+pub use viewer::Viewer;
 
 impl App {
     pub fn new(cli: &Cli) -> Result<Self> {
         let repo = git_ops::find_repository()?;
-        let history = git_ops::load_history(&repo, cli.limit)?;
+        let config = Config::load(&repo, cli)?;
+        Self::with_config(repo, config)
+    }
+
+    /// Open `repo` with an already loaded `config`.
+    pub fn with_config(repo: Repository, config: Config) -> Result<Self> {
+        let history = git_ops::load_history(&repo, config.limit)?;
+        let marks = Marks::load(repo.path())?;
 
         Ok(Self {
             repo,
             history,
-            view: ViewMode::default(),
+            view: config.default_view,
             commit_idx: 0,
             selected_file: None,
+            selection: BTreeSet::new(),
             mouse: MouseState::default(),
             should_quit: false,
             message: None,
             seismic_scroll: 0,
             seismic_filter_inactive: false,
+            seismic_zoom: Zoom::default(),
+            seismic_aggregate: Aggregate::default(),
+            seismic_sort: SortMode::default(),
+            seismic_grouped: false,
+            seismic_collapsed: HashSet::new(),
+            seismic_reveal: None,
+            finder: None,
+            graveyard: None,
+            commit_search: None,
+            pickaxe: None,
+            pending_keys: Vec::new(),
+            palette: None,
+            notice: None,
+            path_filter: None,
+            compare_base: None,
+            playback: Playback::new(config.playback.speed),
+            marks,
+            mark_prompt: None,
+            focus: None,
+            nav_boxes: Vec::new(),
+            layout_rows: Vec::new(),
+            help_scroll: None,
+            restore_prompt: None,
+            restore_undo: Vec::new(),
+            stdout_output: Vec::new(),
+            external: None,
+            viewer: None,
+            line_trace: None,
+            ref_prompt: None,
+            report: None,
+            config,
         })
     }
 
@@ -36,6 +111,10 @@ impl App {
         &self.history
     }
 
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
     pub fn view(&self) -> ViewMode {
         self.view
     }
@@ -52,13 +131,21 @@ impl App {
         self.history.commits.get(self.commit_idx)
     }
 
+    /// Short sha of the current commit, followed by `vs <base>` while comparing.
     pub fn commit_label(&self) -> String {
-        self.current_commit()
-            .map_or_else(|| "---".to_string(), |c| c.short_oid.clone())
+        let label = self
+            .current_commit()
+            .map_or_else(|| "---".to_string(), |c| c.short_oid.clone());
+        match self.compare_label() {
+            Some(base) => format!("{label} {base}"),
+            None => label,
+        }
     }
 
     pub fn files_at_current(&self) -> Vec<(&str, usize)> {
-        self.history.files_at_commit(self.commit_idx)
+        let mut files = self.history.files_at_commit(self.commit_idx);
+        files.retain(|(path, _)| self.passes_path_filter(path));
+        files
     }
 
     pub fn file_health(&self, path: &str) -> Health {
@@ -66,7 +153,7 @@ impl App {
         self.history
             .files
             .get(path)
-            .map(|f| f.health_at(self.commit_idx, prev_idx))
+            .map(|f| f.health_at(self.commit_idx, prev_idx, &self.config.health))
             .unwrap_or_default()
     }
 
@@ -86,30 +173,28 @@ impl App {
         self.message.as_deref()
     }
 
+    /// Short feedback from the last command, shown in the status bar until the next key.
+    pub fn notice(&self) -> Option<&str> {
+        self.notice.as_deref()
+    }
+
+    pub fn clear_notice(&mut self) {
+        self.notice = None;
+    }
+
     pub fn should_quit(&self) -> bool {
         self.should_quit
     }
 
+    /// Commit the current one is compared against: the pinned base, else the parent.
     fn prev_commit_idx(&self) -> Option<usize> {
+        if let Some(base) = self.compare_base_idx() {
+            return Some(base);
+        }
         if self.commit_idx + 1 < self.history.commits.len() {
             Some(self.commit_idx + 1)
         } else {
             None
         }
     }
-
-    pub fn restore_selected(&mut self) -> Result<()> {
-        let Some(path) = &self.selected_file else {
-            return Ok(());
-        };
-
-        let Some(commit) = self.current_commit() else {
-            return Ok(());
-        };
-
-        git_ops::restore_file(&self.repo, commit.oid, path)?;
-
-        self.message = Some(format!("Restored {} from {}", path, commit.short_oid));
-        Ok(())
-    }
 }
@@ -0,0 +1,91 @@
+use crate::error::Result;
+use crate::keymap::{Action, KeyChord, Lookup};
+
+use super::{App, NewRef};
+
+impl App {
+    /// Feed one key press to the keymap, returning the action once a binding is complete.
+    pub fn resolve_key(&mut self, chord: KeyChord) -> Option<Action> {
+        let mut keys = std::mem::take(&mut self.pending_keys);
+        keys.push(chord);
+        match self.config.keymap.lookup(&keys) {
+            Lookup::Action(action) => Some(action),
+            Lookup::Pending => {
+                self.pending_keys = keys;
+                None
+            }
+            // A broken sequence is dropped and its last key tried on its own.
+            Lookup::None if keys.len() > 1 => self.resolve_key(chord),
+            Lookup::None => None,
+        }
+    }
+
+    /// Keys typed so far of an unfinished sequence.
+    pub fn pending_keys(&self) -> &[KeyChord] {
+        &self.pending_keys
+    }
+
+    pub fn perform(&mut self, action: Action) -> Result<()> {
+        self.perform_with(action, "")
+    }
+
+    /// Run an action, passing `arg` to the ones that take an argument.
+    pub fn perform_with(&mut self, action: Action, arg: &str) -> Result<()> {
+        match action {
+            Action::Quit => self.quit(),
+            Action::Restore => self.restore_selected(),
+            Action::OpenExternal => self.open_external()?,
+            Action::ShowFile => self.open_viewer()?,
+            Action::Undo => self.undo_restore(),
+            Action::ClearSelection => self.clear_selection(),
+            Action::NextCommit => self.scroll_timeline(1),
+            Action::PrevCommit => self.scroll_timeline(-1),
+            Action::NextTag => self.jump_to_tag(true),
+            Action::PrevTag => self.jump_to_tag(false),
+            Action::ScrollUp => self.seismic_scroll_vertical(-1),
+            Action::ScrollDown => self.seismic_scroll_vertical(1),
+            Action::FocusLeft => self.move_focus(-1, 0),
+            Action::FocusRight => self.move_focus(1, 0),
+            Action::FocusUp => self.move_focus(0, -1),
+            Action::FocusDown => self.move_focus(0, 1),
+            Action::Activate => self.activate_focus()?,
+            Action::ToggleSelect => self.toggle_select(),
+            Action::SelectDir => self.select_parent_dir(),
+            Action::EnterDir => self.enter_dir(),
+            Action::LeaveDir => self.leave_dir(),
+            Action::NextView => self.next_view(),
+            Action::PrevView => self.prev_view(),
+            Action::View(mode) => self.set_view(mode),
+            Action::ToggleFilter => self.toggle_seismic_filter(),
+            Action::ZoomIn => self.zoom_seismic_in(),
+            Action::ZoomOut => self.zoom_seismic_out(),
+            Action::ToggleAggregate => self.toggle_seismic_aggregate(),
+            Action::CycleSort => self.cycle_seismic_sort(),
+            Action::ToggleGrouping => self.toggle_seismic_grouping(),
+            Action::FindFile => self.open_finder(),
+            Action::Graveyard => self.open_graveyard(),
+            Action::SearchCommits => self.open_commit_search(),
+            Action::Pickaxe => self.open_pickaxe(),
+            Action::TraceLines => self.trace_lines_from(arg)?,
+            Action::NextMatch => self.jump_to_match(true),
+            Action::PrevMatch => self.jump_to_match(false),
+            Action::MarkBase => self.toggle_compare_base(),
+            Action::Help => self.open_help(),
+            Action::TogglePlayback => self.toggle_playback(),
+            Action::PlaybackFaster => self.playback_faster(),
+            Action::PlaybackSlower => self.playback_slower(),
+            Action::SetMark => self.set_mark_from(arg),
+            Action::JumpToMark => self.jump_to_mark_from(arg),
+            Action::CommandPalette => self.open_palette(),
+            Action::CreateBranch => self.create_ref_from(NewRef::Branch, arg),
+            Action::CreateTag => self.create_ref_from(NewRef::Tag, arg),
+            Action::AddWorktree => self.create_ref_from(NewRef::Worktree, arg),
+            Action::Goto => self.goto_revision(arg),
+            Action::SetView => self.set_view_named(arg),
+            Action::SetLimit => self.set_limit(arg),
+            Action::PathFilter => self.set_path_filter(arg),
+            Action::Report => self.open_report(arg),
+        }
+        Ok(())
+    }
+}
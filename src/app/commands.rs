@@ -0,0 +1,158 @@
+use crate::error::TrekError;
+use crate::git_ops;
+use crate::views::ViewMode;
+
+use super::App;
+
+impl App {
+    /// Jump to a commit named by sha, ref or revision expression.
+    pub fn goto_revision(&mut self, rev: &str) {
+        if rev.is_empty() {
+            self.notice = Some("goto needs a commit".to_string());
+            return;
+        }
+        let oid = match self
+            .repo
+            .revparse_single(rev)
+            .and_then(|o| o.peel_to_commit())
+        {
+            Ok(commit) => commit.id(),
+            Err(e) => {
+                self.notice = Some(format!("goto: {}", e.message()));
+                return;
+            }
+        };
+        match self.history.commits.iter().position(|c| c.oid == oid) {
+            Some(idx) => self.commit_idx = idx,
+            None => self.notice = Some(format!("goto: {rev} is not in the loaded history")),
+        }
+    }
+
+    pub fn set_view_named(&mut self, name: &str) {
+        match ViewMode::from_name(name) {
+            Some(mode) => self.set_view(mode),
+            None => self.notice = Some(format!("view: unknown view '{name}'")),
+        }
+    }
+
+    /// Reload history with a new commit limit, staying on the current commit if it is still loaded.
+    pub fn set_limit(&mut self, arg: &str) {
+        let limit = match arg.parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => {
+                self.notice = Some(format!("limit: '{arg}' is not a positive number"));
+                return;
+            }
+        };
+
+        let history = match git_ops::load_history(&self.repo, limit) {
+            Ok(history) => history,
+            Err(TrekError::Git(err)) => {
+                self.notice = Some(format!("limit: {}", err.message()));
+                return;
+            }
+            Err(err) => {
+                self.notice = Some(format!("limit: {err}"));
+                return;
+            }
+        };
+        let current = self.current_commit().map(|c| c.oid);
+        self.history = history;
+        self.config.limit = limit;
+        self.commit_idx = current
+            .and_then(|oid| self.history.commits.iter().position(|c| c.oid == oid))
+            .unwrap_or(0);
+        self.drop_stale_history_state();
+        self.notice = Some(format!("Loaded {} commits", self.history.commits.len()));
+    }
+
+    /// Forget state that refers to commit indices or paths of the history before a reload.
+    fn drop_stale_history_state(&mut self) {
+        if self.compare_base_idx().is_none() {
+            self.compare_base = None;
+        }
+        let files = &self.history.files;
+        let known = |path: &str| {
+            files.contains_key(path)
+                || (path.ends_with('/') && files.keys().any(|f| f.starts_with(path)))
+        };
+        if !self.selected_file.as_deref().is_some_and(known) {
+            self.selected_file = None;
+        }
+        self.selection.retain(|path| known(path));
+        self.seismic_reveal = None;
+        self.focus = None;
+        self.nav_boxes.clear();
+        self.layout_rows.clear();
+        self.commit_search = None;
+        self.pickaxe = None;
+        self.line_trace = None;
+        self.graveyard = None;
+        self.report = None;
+        if let Some(viewer) = &mut self.viewer {
+            viewer.loaded = None;
+        }
+    }
+
+    pub fn path_filter(&self) -> Option<&str> {
+        self.path_filter.as_deref()
+    }
+
+    /// Show only files under `prefix`; an empty prefix shows everything again.
+    pub fn set_path_filter(&mut self, prefix: &str) {
+        self.path_filter = (!prefix.is_empty()).then(|| prefix.to_string());
+        self.seismic_scroll = 0;
+    }
+
+    /// Drill into the next directory down toward the focused or selected file.
+    pub fn enter_dir(&mut self) {
+        let Some(path) = self.focused_row().or_else(|| self.selected_file.clone()) else {
+            self.notice = Some("Select a file first".to_string());
+            return;
+        };
+        let shown = self.path_filter.clone().unwrap_or_default();
+        let Some(rest) = path.strip_prefix(shown.as_str()) else {
+            return;
+        };
+        match rest.split_once('/') {
+            Some((dir, _)) => self.set_path_filter(&format!("{shown}{dir}/")),
+            None => self.notice = Some(format!("{path} is not in a deeper directory")),
+        }
+    }
+
+    /// Go back up from the directory drilled into.
+    pub fn leave_dir(&mut self) {
+        if self.path_filter.is_none() {
+            self.notice = Some("Already showing every file".to_string());
+            return;
+        }
+        self.widen_path_filter();
+    }
+
+    /// Replace the path filter with its parent directory, or clear it at the top.
+    fn widen_path_filter(&mut self) {
+        let Some(prefix) = self.path_filter.as_deref() else {
+            return;
+        };
+        let trimmed = prefix.trim_end_matches('/');
+        let parent = trimmed
+            .rfind('/')
+            .and_then(|i| trimmed.get(..=i))
+            .unwrap_or_default()
+            .to_string();
+        self.set_path_filter(&parent);
+    }
+
+    /// Widen the path filter until it shows `path`.
+    pub(super) fn reveal_in_path_filter(&mut self, path: &str) {
+        while !self.passes_path_filter(path) {
+            self.widen_path_filter();
+        }
+    }
+
+    pub fn passes_path_filter(&self, path: &str) -> bool {
+        self.path_filter
+            .as_deref()
+            .is_none_or(|prefix| path.starts_with(prefix))
+    }
+}
@@ -0,0 +1,23 @@
+use super::App;
+
+impl App {
+    /// Index of the pinned base commit, if one is set and still loaded.
+    pub fn compare_base_idx(&self) -> Option<usize> {
+        let base = self.compare_base?;
+        self.history.commits.iter().position(|c| c.oid == base)
+    }
+
+    /// Pin the current commit as the compare base, or unpin the existing one.
+    pub fn toggle_compare_base(&mut self) {
+        self.compare_base = match self.compare_base {
+            Some(_) => None,
+            None => self.current_commit().map(|c| c.oid),
+        };
+    }
+
+    /// Title suffix such as `vs a1b2c3d4` while comparing.
+    pub fn compare_label(&self) -> Option<String> {
+        let commit = self.history.commits.get(self.compare_base_idx()?)?;
+        Some(format!("vs {}", commit.short_oid))
+    }
+}
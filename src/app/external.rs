@@ -0,0 +1,113 @@
+use std::io;
+#[cfg(unix)]
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus};
+
+use crate::error::Result;
+use crate::git_ops;
+
+use super::App;
+
+/// A historical file version waiting to be shown by an outside program.
+#[derive(Clone, Debug)]
+pub struct ExternalOpen {
+    /// `$EDITOR` or `$PAGER` as set, which may carry its own arguments.
+    pub command: String,
+    pub file: PathBuf,
+}
+
+impl ExternalOpen {
+    /// The command to run; with stdout redirected it writes to stderr, like the UI.
+    pub fn command(&self) -> io::Result<Command> {
+        // The shell honors quoting in the command, as git does for `$EDITOR`.
+        #[cfg(unix)]
+        let mut command = {
+            let mut command = Command::new("sh");
+            command
+                .arg("-c")
+                .arg(format!("{} \"$@\"", self.command))
+                .arg(&self.command)
+                .arg(&self.file);
+            command
+        };
+        // Elsewhere the command is split on whitespace, so a program or argument
+        // containing spaces cannot be named.
+        #[cfg(not(unix))]
+        let command = {
+            let mut words = self.command.split_whitespace();
+            let program = words
+                .next()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no program to run"))?;
+            let mut command = Command::new(program);
+            command.args(words).arg(&self.file);
+            command
+        };
+        // Other platforms leave stdout inherited.
+        #[cfg(unix)]
+        if !io::stdout().is_terminal() {
+            use std::os::fd::AsFd;
+            use std::process::Stdio;
+            command.stdout(Stdio::from(io::stderr().as_fd().try_clone_to_owned()?));
+        }
+        Ok(command)
+    }
+
+    fn program(&self) -> &str {
+        self.command.split_whitespace().next().unwrap_or_default()
+    }
+}
+
+/// `$EDITOR`, else `$PAGER`, else `less`.
+fn viewer_command() -> String {
+    ["EDITOR", "PAGER"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|command| !command.trim().is_empty())
+        .unwrap_or_else(|| "less".to_string())
+}
+
+impl App {
+    /// Write the selected file as of the current commit to a temporary file and queue
+    /// it to be opened once the terminal is handed over.
+    pub fn open_external(&mut self) -> Result<()> {
+        let Some(path) = self.selected_file.clone() else {
+            self.notice = Some("Select a file to open".to_string());
+            return Ok(());
+        };
+        let Some(commit) = self.current_commit() else {
+            return Ok(());
+        };
+        let exists = self
+            .history
+            .files
+            .get(&path)
+            .is_some_and(|f| f.lines_at(self.commit_idx).is_some());
+        if !exists {
+            self.notice = Some(format!("{path} does not exist at {}", commit.short_oid));
+            return Ok(());
+        }
+
+        let file = git_ops::temp_copy(&self.repo, commit.oid, &path)?;
+        self.external = Some(ExternalOpen {
+            command: viewer_command(),
+            file,
+        });
+        Ok(())
+    }
+
+    /// The file queued by [`App::open_external`], taken so it is opened once.
+    pub fn take_external(&mut self) -> Option<ExternalOpen> {
+        self.external.take()
+    }
+
+    /// Report how the outside program ended and delete the temporary file.
+    pub fn finish_external(&mut self, open: &ExternalOpen, status: io::Result<ExitStatus>) {
+        git_ops::remove_temp_copy(&open.file);
+        self.notice = match status {
+            Ok(status) if status.success() => None,
+            Ok(status) => Some(format!("{} exited with {status}", open.program())),
+            Err(e) => Some(format!("Could not run {}: {e}", open.program())),
+        };
+    }
+}
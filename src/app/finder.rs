@@ -0,0 +1,88 @@
+use crate::data::parent_dir;
+use crate::fuzzy::{self, Match};
+
+use super::App;
+
+/// State of the fuzzy file finder overlay.
+#[derive(Clone, Debug, Default)]
+pub struct Finder {
+    pub query: String,
+    pub matches: Vec<Match>,
+    pub selected: usize,
+}
+
+impl App {
+    pub fn finder(&self) -> Option<&Finder> {
+        self.finder.as_ref()
+    }
+
+    pub fn open_finder(&mut self) {
+        self.finder = Some(Finder::default());
+        self.refresh_finder();
+    }
+
+    pub fn close_finder(&mut self) {
+        self.finder = None;
+    }
+
+    pub fn finder_input(&mut self, c: char) {
+        if let Some(finder) = &mut self.finder {
+            finder.query.push(c);
+        }
+        self.refresh_finder();
+    }
+
+    pub fn finder_backspace(&mut self) {
+        if let Some(finder) = &mut self.finder {
+            finder.query.pop();
+        }
+        self.refresh_finder();
+    }
+
+    pub fn finder_move(&mut self, delta: isize) {
+        let Some(finder) = &mut self.finder else {
+            return;
+        };
+        let max = finder.matches.len().saturating_sub(1);
+        finder.selected = finder.selected.saturating_add_signed(delta).min(max);
+    }
+
+    /// Select the highlighted file, or the file at `index` when given.
+    pub fn finder_accept(&mut self, index: Option<usize>) {
+        let Some(finder) = self.finder.take() else {
+            return;
+        };
+        let chosen = index.unwrap_or(finder.selected);
+        let Some(path) = finder.matches.into_iter().nth(chosen).map(|m| m.text) else {
+            return;
+        };
+        self.reveal_file(path);
+    }
+
+    /// Select a file and bring it into view in Seismic and Terrain.
+    pub fn reveal_file(&mut self, path: String) {
+        self.reveal_in_path_filter(&path);
+        self.seismic_collapsed.remove(&parent_dir(&path));
+        self.seismic_reveal = Some(path.clone());
+        self.selected_file = Some(path);
+    }
+
+    /// Scroll Seismic to a pending revealed file once its rendered row order is known.
+    pub fn apply_seismic_reveal(&mut self, rows: &[String]) {
+        let Some(path) = &self.seismic_reveal else {
+            return;
+        };
+        if let Some(row) = rows.iter().position(|r| r == path) {
+            self.seismic_scroll = row.saturating_sub(2);
+            self.seismic_reveal = None;
+        }
+    }
+
+    fn refresh_finder(&mut self) {
+        let Some(finder) = &mut self.finder else {
+            return;
+        };
+        finder.matches = fuzzy::rank(&finder.query, self.history.files.keys().map(String::as_str));
+        finder.selected = 0;
+    }
+}
@@ -0,0 +1,181 @@
+use crate::error::Result;
+use crate::mouse::{HitBox, HitTarget};
+use crate::views::ViewMode;
+
+use super::App;
+
+impl App {
+    /// Whether an element is hovered by the mouse or holds the keyboard focus.
+    pub fn is_emphasized(&self, hit_box: &HitBox) -> bool {
+        let hovered =
+            self.mouse.hover == hit_box.target && hit_box.contains(self.mouse.x, self.mouse.y);
+        let focused = self.focus.as_ref().is_some_and(|focus| {
+            focus.target == hit_box.target && (focus.rect == hit_box.rect || is_row(&focus.target))
+        });
+        hovered || focused
+    }
+
+    /// Move the focus to the nearest element in the direction `(dx, dy)`.
+    pub fn move_focus(&mut self, dx: i32, dy: i32) {
+        let Some(current) = &self.focus else {
+            self.focus = self.initial_focus();
+            return;
+        };
+        let (cx, cy) = center(current);
+
+        let best = self
+            .nav_boxes
+            .iter()
+            .filter(|b| b.rect != current.rect)
+            .filter_map(|b| {
+                let (x, y) = center(b);
+                let along = (x - cx) * dx + (y - cy) * dy;
+                let across = if dx == 0 { x - cx } else { y - cy }.abs();
+                (along > 0).then_some((along + 2 * across, b))
+            })
+            .min_by_key(|(score, _)| *score)
+            .map(|(_, b)| b.clone());
+
+        match best {
+            Some(next) => self.focus = Some(next),
+            None if dy != 0 && self.view == ViewMode::Seismic => self.scroll_focus_row(dy),
+            None => {}
+        }
+    }
+
+    /// Act on the focused element as if it had been clicked.
+    pub fn activate_focus(&mut self) -> Result<()> {
+        let Some(focus) = self.focus.clone() else {
+            return Ok(());
+        };
+        if matches!(
+            focus.target,
+            HitTarget::SeismicCell(_) | HitTarget::SeismicBucket(_)
+        ) {
+            if let Some(path) = self.focused_row().filter(|p| !p.ends_with('/')) {
+                self.selected_file = Some(path);
+            }
+        }
+        self.handle_click(focus.target)
+    }
+
+    /// Path of the focused file or directory row, or of the row holding the focused cell.
+    pub(super) fn focused_row(&self) -> Option<String> {
+        let focus = self.focus.as_ref()?;
+        let target = if is_row(&focus.target) {
+            &focus.target
+        } else {
+            &self
+                .nav_boxes
+                .iter()
+                .find(|b| b.rect.y == focus.rect.y && is_row(&b.target))?
+                .target
+        };
+        match target {
+            HitTarget::File(path) | HitTarget::SeismicDir(path) => Some(path.clone()),
+            _ => None,
+        }
+    }
+
+    /// Remember the navigable elements of the last frame and keep the focus on the same element.
+    pub fn record_layout(&mut self, hit_boxes: &[HitBox], rows: &[String]) {
+        let nav: Vec<HitBox> = hit_boxes
+            .iter()
+            .filter(|b| is_navigable(&b.target))
+            .cloned()
+            .collect();
+        if nav.is_empty() {
+            return;
+        }
+
+        if let Some(focus) = &self.focus {
+            let same: Vec<&HitBox> = nav.iter().filter(|b| b.target == focus.target).collect();
+            let pool = if same.is_empty() {
+                nav.iter().collect()
+            } else {
+                same
+            };
+            let (fx, fy) = center(focus);
+            self.focus = pool
+                .into_iter()
+                .min_by_key(|b| {
+                    let (x, y) = center(b);
+                    (x - fx).abs() + (y - fy).abs()
+                })
+                .cloned();
+        }
+
+        self.nav_boxes = nav;
+        self.layout_rows = rows.to_vec();
+    }
+
+    /// Start on the selected file, otherwise on the first row or rectangle.
+    fn initial_focus(&self) -> Option<HitBox> {
+        let selected = self.selected_file.as_ref().and_then(|path| {
+            self.nav_boxes
+                .iter()
+                .find(|b| b.target == HitTarget::File(path.clone()))
+        });
+        selected
+            .or_else(|| self.nav_boxes.iter().find(|b| is_row(&b.target)))
+            .or_else(|| self.nav_boxes.first())
+            .cloned()
+    }
+
+    /// Scroll Seismic past the edge of the visible rows, taking a focused row name along.
+    fn scroll_focus_row(&mut self, dy: i32) {
+        let before = self.seismic_scroll;
+        let max = self.layout_rows.len().saturating_sub(1);
+        if dy > 0 && before >= max {
+            return;
+        }
+        self.seismic_scroll_vertical(dy);
+        if self.seismic_scroll == before {
+            return;
+        }
+
+        let Some(focus) = &mut self.focus else {
+            return;
+        };
+        let (HitTarget::File(path) | HitTarget::SeismicDir(path)) = &focus.target else {
+            return;
+        };
+        let next = self
+            .layout_rows
+            .iter()
+            .position(|row| row == path)
+            .and_then(|i| i.checked_add_signed(dy as isize))
+            .and_then(|i| self.layout_rows.get(i));
+        if let Some(row) = next {
+            focus.target = if row.ends_with('/') {
+                HitTarget::SeismicDir(row.clone())
+            } else {
+                HitTarget::File(row.clone())
+            };
+        }
+    }
+}
+
+fn is_navigable(target: &HitTarget) -> bool {
+    matches!(
+        target,
+        HitTarget::File(_)
+            | HitTarget::SeismicCell(_)
+            | HitTarget::SeismicBucket(_)
+            | HitTarget::SeismicDir(_)
+    )
+}
+
+/// Targets that name a whole row or rectangle rather than one grid cell.
+fn is_row(target: &HitTarget) -> bool {
+    matches!(target, HitTarget::File(_) | HitTarget::SeismicDir(_))
+}
+
+/// Center of a hit box in half-cell units.
+fn center(hit_box: &HitBox) -> (i32, i32) {
+    let r = hit_box.rect;
+    (
+        i32::from(r.x) * 2 + i32::from(r.width),
+        i32::from(r.y) * 2 + i32::from(r.height),
+    )
+}
@@ -0,0 +1,57 @@
+use crate::data::Grave;
+
+use super::App;
+
+/// State of the graveyard overlay listing deleted files.
+#[derive(Clone, Debug, Default)]
+pub struct Graveyard {
+    pub graves: Vec<Grave>,
+    pub selected: usize,
+}
+
+impl App {
+    pub fn graveyard(&self) -> Option<&Graveyard> {
+        self.graveyard.as_ref()
+    }
+
+    /// List the files deleted within the loaded history that pass the path filter.
+    pub fn open_graveyard(&mut self) {
+        let mut graves = self.history.graveyard();
+        graves.retain(|grave| self.passes_path_filter(&grave.path));
+        if graves.is_empty() {
+            self.notice = Some("No files were deleted in the loaded history".to_string());
+            return;
+        }
+        self.graveyard = Some(Graveyard {
+            graves,
+            selected: 0,
+        });
+    }
+
+    pub fn close_graveyard(&mut self) {
+        self.graveyard = None;
+    }
+
+    pub fn graveyard_move(&mut self, delta: isize) {
+        let Some(graveyard) = &mut self.graveyard else {
+            return;
+        };
+        let max = graveyard.graves.len().saturating_sub(1);
+        graveyard.selected = graveyard.selected.saturating_add_signed(delta).min(max);
+    }
+
+    /// Go to the last version of the highlighted file (the one at `index` when given)
+    /// and ask to restore it.
+    pub fn graveyard_accept(&mut self, index: Option<usize>) {
+        let Some(graveyard) = self.graveyard.take() else {
+            return;
+        };
+        let chosen = index.unwrap_or(graveyard.selected);
+        let Some(grave) = graveyard.graves.into_iter().nth(chosen) else {
+            return;
+        };
+        self.commit_idx = grave.last_seen;
+        self.reveal_file(grave.path.clone());
+        self.restore_file_at(&grave.path, grave.last_seen);
+    }
+}
@@ -0,0 +1,22 @@
+use super::App;
+
+impl App {
+    /// Scroll offset of the help overlay, or `None` when it is closed.
+    pub fn help_scroll(&self) -> Option<usize> {
+        self.help_scroll
+    }
+
+    pub fn open_help(&mut self) {
+        self.help_scroll = Some(0);
+    }
+
+    pub fn close_help(&mut self) {
+        self.help_scroll = None;
+    }
+
+    pub fn scroll_help(&mut self, delta: isize) {
+        if let Some(scroll) = &mut self.help_scroll {
+            *scroll = scroll.saturating_add_signed(delta);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::marks::Mark;
+
+use super::App;
+
+/// A mark key that is waiting for its letter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarkPrompt {
+    Set,
+    Jump,
+}
+
+impl App {
+    pub fn mark_prompt(&self) -> Option<MarkPrompt> {
+        self.mark_prompt
+    }
+
+    pub fn cancel_mark_prompt(&mut self) {
+        self.mark_prompt = None;
+    }
+
+    /// Mark the current commit with `arg` (`<letter> [note]`), or wait for a letter.
+    pub fn set_mark_from(&mut self, arg: &str) {
+        let mut chars = arg.chars();
+        match chars.next() {
+            Some(letter) => self.set_mark(letter, chars.as_str().trim()),
+            None => self.mark_prompt = Some(MarkPrompt::Set),
+        }
+    }
+
+    /// Jump to the mark named by the first letter of `arg`, or wait for a letter.
+    pub fn jump_to_mark_from(&mut self, arg: &str) {
+        match arg.chars().next() {
+            Some(letter) => self.jump_to_mark(letter),
+            None => self.mark_prompt = Some(MarkPrompt::Jump),
+        }
+    }
+
+    /// Finish a pending mark key with its letter.
+    pub fn mark_prompt_letter(&mut self, letter: char) {
+        match self.mark_prompt.take() {
+            Some(MarkPrompt::Set) => self.set_mark(letter, ""),
+            Some(MarkPrompt::Jump) => self.jump_to_mark(letter),
+            None => {}
+        }
+    }
+
+    /// Mark the current commit; re-marking the same commit keeps its note unless a new one is given.
+    pub fn set_mark(&mut self, letter: char, note: &str) {
+        if !letter.is_ascii_alphabetic() {
+            self.notice = Some(format!("'{letter}' is not a mark letter"));
+            return;
+        }
+        let Some(oid) = self.current_commit().map(|c| c.oid) else {
+            return;
+        };
+        let note = if note.is_empty() {
+            self.marks
+                .get(letter)
+                .filter(|m| m.oid == oid)
+                .and_then(|m| m.note.clone())
+        } else {
+            Some(note.to_string())
+        };
+
+        self.notice = Some(match self.marks.set(letter, Mark { oid, note }) {
+            Ok(()) => format!("Marked {} as '{letter}'", self.commit_label()),
+            Err(err) => format!("marks: {err}"),
+        });
+    }
+
+    pub fn jump_to_mark(&mut self, letter: char) {
+        let Some(mark) = self.marks.get(letter) else {
+            self.notice = Some(format!("No mark '{letter}'"));
+            return;
+        };
+        match self.history.commits.iter().position(|c| c.oid == mark.oid) {
+            Some(idx) => self.commit_idx = idx,
+            None => self.notice = Some(format!("Mark '{letter}' is not in the loaded history")),
+        }
+    }
+
+    /// Loaded commit indices that carry a mark, with the mark letter.
+    pub fn marked_commits(&self) -> Vec<(usize, char)> {
+        self.marks
+            .iter()
+            .filter_map(|(letter, mark)| {
+                let idx = self
+                    .history
+                    .commits
+                    .iter()
+                    .position(|c| c.oid == mark.oid)?;
+                Some((idx, letter))
+            })
+            .collect()
+    }
+
+    /// Marks on the current commit, as `(letter, note)`.
+    pub fn marks_at_current(&self) -> Vec<(char, Option<&str>)> {
+        let Some(oid) = self.current_commit().map(|c| c.oid) else {
+            return Vec::new();
+        };
+        self.marks
+            .iter()
+            .filter(|(_, mark)| mark.oid == oid)
+            .map(|(letter, mark)| (letter, mark.note.as_deref()))
+            .collect()
+    }
+}
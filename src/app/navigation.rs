@@ -1,32 +1,12 @@
+use crate::data::Commit;
+use crate::error::Result;
 use crate::mouse::HitTarget;
 use crate::views::ViewMode;
 
 use super::App;
 
 impl App {
-    pub fn seismic_scroll(&self) -> usize {
-        self.seismic_scroll
-    }
-
-    pub fn seismic_scroll_vertical(&mut self, delta: i32) {
-        let amount = delta.unsigned_abs() as usize;
-        if delta > 0 {
-            self.seismic_scroll = self.seismic_scroll.saturating_add(amount);
-        } else {
-            self.seismic_scroll = self.seismic_scroll.saturating_sub(amount);
-        }
-    }
-
-    pub fn seismic_filter_inactive(&self) -> bool {
-        self.seismic_filter_inactive
-    }
-
-    pub fn toggle_seismic_filter(&mut self) {
-        self.seismic_filter_inactive = !self.seismic_filter_inactive;
-        self.seismic_scroll = 0;
-    }
-
-    pub fn handle_click(&mut self, target: HitTarget) {
+    pub fn handle_click(&mut self, target: HitTarget) -> Result<()> {
         match target {
             HitTarget::File(path) => {
                 self.selected_file = Some(path);
@@ -38,8 +18,25 @@ impl App {
             HitTarget::SeismicCell(commit_idx) => {
                 self.commit_idx = commit_idx;
             }
+            HitTarget::SeismicBucket(commit_idx) => {
+                self.commit_idx = commit_idx;
+                self.zoom_seismic_in();
+            }
+            HitTarget::SeismicDir(dir) => {
+                self.toggle_seismic_dir(&dir);
+            }
+            HitTarget::FinderItem(i) => {
+                self.finder_accept(Some(i));
+            }
+            HitTarget::PaletteItem(i) => {
+                self.palette_accept(Some(i))?;
+            }
+            HitTarget::GraveyardItem(i) => {
+                self.graveyard_accept(Some(i));
+            }
             HitTarget::None => {}
         }
+        Ok(())
     }
 
     pub fn scroll_timeline(&mut self, delta: isize) {
@@ -52,6 +49,23 @@ impl App {
         self.commit_idx = new_idx.min(max);
     }
 
+    /// Jump to the nearest tagged commit that is older, or newer, than the current one.
+    pub fn jump_to_tag(&mut self, older: bool) {
+        let current = self.commit_idx;
+        let tagged = |(_, c): &(usize, &Commit)| c.is_tagged();
+        let commits = self.history.commits.iter().enumerate();
+        let target = if older {
+            commits.skip(current + 1).find(tagged)
+        } else {
+            commits.take(current).rev().find(tagged)
+        };
+        match target {
+            Some((idx, _)) => self.commit_idx = idx,
+            None if older => self.notice = Some("No older tag in the loaded history".to_string()),
+            None => self.notice = Some("No newer tag".to_string()),
+        }
+    }
+
     pub fn set_view(&mut self, mode: ViewMode) {
         self.view = mode;
         self.seismic_scroll = 0;
@@ -69,6 +83,11 @@ impl App {
 
     pub fn clear_selection(&mut self) {
         self.selected_file = None;
+        self.selection.clear();
+        self.focus = None;
+        self.commit_search = None;
+        self.pickaxe = None;
+        self.line_trace = None;
     }
 
     pub fn quit(&mut self) {
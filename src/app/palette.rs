@@ -0,0 +1,109 @@
+use crate::error::Result;
+use crate::fuzzy::{self, Match};
+use crate::keymap::Action;
+
+use super::App;
+
+/// State of the `:` command palette.
+#[derive(Clone, Debug, Default)]
+pub struct Palette {
+    /// Command name, optionally followed by an argument.
+    pub query: String,
+    /// Action names matching the command part of the query.
+    pub matches: Vec<Match>,
+    pub selected: usize,
+}
+
+impl Palette {
+    /// The command part of the query and its trimmed argument.
+    pub fn split(&self) -> (&str, &str) {
+        match self.query.split_once(char::is_whitespace) {
+            Some((command, arg)) => (command, arg.trim()),
+            None => (self.query.as_str(), ""),
+        }
+    }
+}
+
+impl App {
+    pub fn palette(&self) -> Option<&Palette> {
+        self.palette.as_ref()
+    }
+
+    pub fn open_palette(&mut self) {
+        self.palette = Some(Palette::default());
+        self.refresh_palette();
+    }
+
+    pub fn close_palette(&mut self) {
+        self.palette = None;
+    }
+
+    pub fn palette_input(&mut self, c: char) {
+        if let Some(palette) = &mut self.palette {
+            palette.query.push(c);
+        }
+        self.refresh_palette();
+    }
+
+    pub fn palette_backspace(&mut self) {
+        if let Some(palette) = &mut self.palette {
+            palette.query.pop();
+        }
+        self.refresh_palette();
+    }
+
+    pub fn palette_move(&mut self, delta: isize) {
+        let Some(palette) = &mut self.palette else {
+            return;
+        };
+        let max = palette.matches.len().saturating_sub(1);
+        palette.selected = palette.selected.saturating_add_signed(delta).min(max);
+    }
+
+    /// Run the typed command, or the highlighted one (the one at `index` when given).
+    pub fn palette_accept(&mut self, index: Option<usize>) -> Result<()> {
+        let Some(palette) = self.palette.take() else {
+            return Ok(());
+        };
+        let (command, arg) = palette.split();
+        let chosen = palette
+            .matches
+            .get(index.unwrap_or(palette.selected))
+            .and_then(|m| Action::from_name(&m.text));
+        let action = match index {
+            Some(_) => chosen,
+            None => Action::from_name(command).or(chosen),
+        };
+
+        match action {
+            // Picking a command that needs an argument fills in its name so it can be typed.
+            Some(action) if action.takes_argument() && !palette.query.contains(' ') => {
+                self.palette = Some(Palette {
+                    query: format!("{} ", action.name()),
+                    ..palette
+                });
+                self.refresh_palette();
+                Ok(())
+            }
+            Some(action) => self.perform_with(action, arg),
+            None => {
+                self.notice = Some(format!("unknown command '{command}'"));
+                Ok(())
+            }
+        }
+    }
+
+    fn refresh_palette(&mut self) {
+        let Some(palette) = &mut self.palette else {
+            return;
+        };
+        let (command, _) = palette.split();
+        let names = Action::ALL.iter().map(|a| a.name());
+        palette.matches = if command.is_empty() {
+            names.filter_map(|name| fuzzy::score("", name)).collect()
+        } else {
+            fuzzy::rank(command, names)
+        };
+        palette.selected = 0;
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::sync::mpsc::Receiver;
+
+use crate::git_ops::{self, PickaxeEvent};
+
+use super::App;
+
+/// State of a pickaxe search for commits that add or remove a string.
+#[derive(Debug, Default)]
+pub struct Pickaxe {
+    pub query: String,
+    /// True while the query is still being typed.
+    pub editing: bool,
+    /// File the search was restricted to, if any.
+    pub scope: Option<String>,
+    /// Matching commit indices in scan order (newest first).
+    pub commits: Vec<usize>,
+    /// Paths that matched at each commit.
+    pub paths: HashMap<usize, Vec<String>>,
+    pub scanned: usize,
+    pub done: bool,
+    pub error: Option<String>,
+    receiver: Option<Receiver<PickaxeEvent>>,
+}
+
+impl Pickaxe {
+    /// Whether `path` gained or lost occurrences at `commit_idx`.
+    pub fn is_hit(&self, path: &str, commit_idx: usize) -> bool {
+        self.paths
+            .get(&commit_idx)
+            .is_some_and(|paths| paths.iter().any(|p| p == path))
+    }
+}
+
+impl App {
+    pub fn pickaxe(&self) -> Option<&Pickaxe> {
+        self.pickaxe.as_ref()
+    }
+
+    pub fn open_pickaxe(&mut self) {
+        self.commit_search = None;
+        self.line_trace = None;
+        self.pickaxe = Some(Pickaxe {
+            editing: true,
+            ..Pickaxe::default()
+        });
+    }
+
+    pub fn clear_pickaxe(&mut self) {
+        self.pickaxe = None;
+    }
+
+    pub fn pickaxe_input(&mut self, c: char) {
+        if let Some(pickaxe) = &mut self.pickaxe {
+            pickaxe.query.push(c);
+        }
+    }
+
+    pub fn pickaxe_backspace(&mut self) {
+        if let Some(pickaxe) = &mut self.pickaxe {
+            pickaxe.query.pop();
+        }
+    }
+
+    /// Start scanning the loaded commits, scoped to the selected file if there is one.
+    pub fn pickaxe_confirm(&mut self) {
+        let scope = self.selected_file.clone();
+        let repo_path = self.repo.path().to_path_buf();
+        let oids = self.history.commits.iter().map(|c| c.oid).collect();
+        let Some(pickaxe) = &mut self.pickaxe else {
+            return;
+        };
+        pickaxe.editing = false;
+
+        match git_ops::compile_needle(&pickaxe.query) {
+            Ok(needle) => {
+                pickaxe.receiver = Some(git_ops::spawn_pickaxe(
+                    repo_path,
+                    oids,
+                    needle,
+                    scope.clone(),
+                ));
+                pickaxe.scope = scope;
+            }
+            Err(e) => {
+                pickaxe.error = Some(e.to_string());
+                pickaxe.done = true;
+            }
+        }
+    }
+
+    /// Drain results sent by the background scan.
+    pub fn poll_pickaxe(&mut self) {
+        let Some(pickaxe) = &mut self.pickaxe else {
+            return;
+        };
+        let Some(receiver) = &pickaxe.receiver else {
+            return;
+        };
+
+        let events: Vec<PickaxeEvent> = receiver.try_iter().collect();
+        for event in events {
+            match event {
+                PickaxeEvent::Hit { commit_idx, paths } => {
+                    pickaxe.commits.push(commit_idx);
+                    pickaxe.paths.insert(commit_idx, paths);
+                }
+                PickaxeEvent::Scanned(n) => pickaxe.scanned = n,
+                PickaxeEvent::Done => pickaxe.done = true,
+                PickaxeEvent::Failed(e) => {
+                    pickaxe.error = Some(e);
+                    pickaxe.done = true;
+                }
+            }
+        }
+
+        if pickaxe.done {
+            pickaxe.receiver = None;
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::time::{Duration, Instant};
+
+use crate::config::Pace;
+
+use super::App;
+
+const MIN_SPEED: f64 = 0.25;
+const MAX_SPEED: f64 = 20.0;
+const SPEED_STEP: f64 = 1.5;
+
+/// State of history playback, which walks from older to newer commits.
+#[derive(Clone, Copy, Debug)]
+pub struct Playback {
+    /// Commits per second.
+    pub speed: f64,
+    /// When the last step happened; `None` while paused.
+    last_step: Option<Instant>,
+}
+
+impl Playback {
+    pub fn new(speed: f64) -> Self {
+        Self {
+            speed,
+            last_step: None,
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.last_step.is_some()
+    }
+}
+
+impl App {
+    pub fn playback(&self) -> &Playback {
+        &self.playback
+    }
+
+    /// Start or pause playback; starting at the newest commit rewinds to the oldest.
+    pub fn toggle_playback(&mut self) {
+        if self.playback.is_playing() {
+            self.playback.last_step = None;
+            return;
+        }
+        if self.commit_idx == 0 {
+            self.commit_idx = self.commit_count().saturating_sub(1);
+        }
+        self.playback.last_step = Some(Instant::now());
+    }
+
+    pub fn playback_faster(&mut self) {
+        self.playback.speed = (self.playback.speed * SPEED_STEP).min(MAX_SPEED);
+    }
+
+    pub fn playback_slower(&mut self) {
+        self.playback.speed = (self.playback.speed / SPEED_STEP).max(MIN_SPEED);
+    }
+
+    /// Advance playback if the wait for the next commit has passed.
+    pub fn tick_playback(&mut self) {
+        let Some(last_step) = self.playback.last_step else {
+            return;
+        };
+        if self.commit_idx == 0 {
+            self.playback.last_step = None;
+            return;
+        }
+        if last_step.elapsed() < self.step_delay() {
+            return;
+        }
+
+        self.commit_idx -= 1;
+        self.playback.last_step = Some(Instant::now());
+
+        if self.commit_idx == 0 {
+            self.playback.last_step = None;
+            self.notice = Some("Playback reached the newest commit".to_string());
+        } else if let Some(reason) = self.playback_stop_reason() {
+            self.playback.last_step = None;
+            self.notice = Some(reason);
+        }
+    }
+
+    /// Wait before stepping from the current commit to the next newer one.
+    fn step_delay(&self) -> Duration {
+        let steady = 1.0 / self.playback.speed;
+        let seconds = match self.config.playback.pace {
+            Pace::Steady => steady,
+            Pace::Time => self.time_scaled_delay(steady),
+        };
+        Duration::from_secs_f64(seconds)
+    }
+
+    /// Scale the steady wait by this gap relative to the average gap between loaded commits.
+    fn time_scaled_delay(&self, steady: f64) -> f64 {
+        let commits = &self.history.commits;
+        let (Some(newest), Some(oldest)) = (commits.first(), commits.last()) else {
+            return steady;
+        };
+        let (Some(current), Some(next)) = (
+            commits.get(self.commit_idx),
+            commits.get(self.commit_idx.wrapping_sub(1)),
+        ) else {
+            return steady;
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let average = (newest.time - oldest.time) as f64 / commits.len().saturating_sub(1) as f64;
+        if average <= 0.0 {
+            return steady;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let gap = (next.time - current.time).max(0) as f64;
+        (steady * gap / average).clamp(steady / 10.0, steady * 10.0)
+    }
+
+    /// Why playback should pause at the current commit, if it should.
+    fn playback_stop_reason(&self) -> Option<String> {
+        if let Some((letter, note)) = self.marks_at_current().into_iter().next() {
+            let note = note.map_or_else(String::new, |n| format!(": {n}"));
+            return Some(format!("Paused at mark '{letter}'{note}"));
+        }
+
+        let min_delta = self
+            .config
+            .playback
+            .stop_at
+            .min_delta(&self.config.intensity)?;
+        let idx = self.commit_idx;
+        let (path, file, delta) = self
+            .history
+            .files
+            .iter()
+            .filter_map(|(path, file)| Some((path, file, file.size_change_at(idx, idx + 1)?)))
+            .max_by_key(|(_, _, delta)| delta.unsigned_abs())?;
+
+        let reached = usize::try_from(delta.unsigned_abs()).is_ok_and(|d| d >= min_delta);
+        let change = match (file.lines_at(idx + 1), file.lines_at(idx)) {
+            (None, _) => format!("created with {delta} lines"),
+            (_, None) => format!("deleted with {} lines", delta.unsigned_abs()),
+            _ => format!("changed by {delta:+} lines"),
+        };
+        reached.then(|| format!("Paused: {path} {change}"))
+    }
+}
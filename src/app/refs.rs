@@ -0,0 +1,159 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::TrekError;
+use crate::git_ops;
+
+use super::App;
+
+/// Kind of ref or worktree to create at the current commit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NewRef {
+    Branch,
+    Tag,
+    Worktree,
+}
+
+impl NewRef {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Branch => "branch",
+            Self::Tag => "tag",
+            Self::Worktree => "worktree",
+        }
+    }
+
+    /// Label of the input prompt.
+    pub fn prompt(self) -> &'static str {
+        match self {
+            Self::Branch => "New branch:",
+            Self::Tag => "New tag (name [message for an annotated tag]):",
+            Self::Worktree => "New worktree (path beside the repository [branch]):",
+        }
+    }
+}
+
+/// Name being typed for a new branch, tag or worktree.
+#[derive(Clone, Debug)]
+pub struct RefPrompt {
+    pub kind: NewRef,
+    pub input: String,
+}
+
+impl App {
+    pub fn ref_prompt(&self) -> Option<&RefPrompt> {
+        self.ref_prompt.as_ref()
+    }
+
+    /// Create `kind` from `arg` at the current commit, or ask for it when `arg` is empty.
+    pub fn create_ref_from(&mut self, kind: NewRef, arg: &str) {
+        if arg.trim().is_empty() {
+            self.ref_prompt = Some(RefPrompt {
+                kind,
+                input: String::new(),
+            });
+            return;
+        }
+        self.create_ref(kind, arg.trim());
+    }
+
+    pub fn cancel_ref_prompt(&mut self) {
+        self.ref_prompt = None;
+    }
+
+    pub fn ref_prompt_input(&mut self, c: char) {
+        if let Some(prompt) = &mut self.ref_prompt {
+            prompt.input.push(c);
+        }
+    }
+
+    pub fn ref_prompt_backspace(&mut self) {
+        if let Some(prompt) = &mut self.ref_prompt {
+            prompt.input.pop();
+        }
+    }
+
+    /// Create what the prompt asked for; an empty input keeps the prompt open.
+    pub fn confirm_ref_prompt(&mut self) {
+        let Some(prompt) = self.ref_prompt.take() else {
+            return;
+        };
+        let input = prompt.input.trim().to_string();
+        if input.is_empty() {
+            self.ref_prompt = Some(prompt);
+            return;
+        }
+        self.create_ref(prompt.kind, &input);
+    }
+
+    /// Create the branch, tag or worktree described by `input`, reporting git's
+    /// refusal (a name in use, an invalid name) as a notice.
+    fn create_ref(&mut self, kind: NewRef, input: &str) {
+        let Some((oid, short_oid)) = self.current_commit().map(|c| (c.oid, c.short_oid.clone()))
+        else {
+            return;
+        };
+        let (first, rest) = input
+            .split_once(char::is_whitespace)
+            .map_or((input, ""), |(first, rest)| (first, rest.trim()));
+
+        let (created, done) = match kind {
+            NewRef::Branch => {
+                if !rest.is_empty() {
+                    self.notice = Some(format!("branch: '{input}' contains spaces"));
+                    return;
+                }
+                (
+                    git_ops::create_branch(&self.repo, oid, first),
+                    format!("Created branch {first}"),
+                )
+            }
+            NewRef::Tag => {
+                let message = (!rest.is_empty()).then_some(rest);
+                let kind = if message.is_some() {
+                    "annotated tag"
+                } else {
+                    "tag"
+                };
+                (
+                    git_ops::create_tag(&self.repo, oid, first, message),
+                    format!("Created {kind} {first}"),
+                )
+            }
+            NewRef::Worktree => {
+                let path = self.worktree_path(first);
+                // libgit2 leaves half-made worktree metadata behind when the
+                // directory is in the way, so refuse before touching anything.
+                if path.exists() {
+                    self.notice = Some(format!("worktree: {} already exists", path.display()));
+                    return;
+                }
+                let branch = match rest {
+                    "" => Path::new(first)
+                        .file_name()
+                        .map_or_else(|| first.to_string(), |n| n.to_string_lossy().into_owned()),
+                    branch => branch.to_string(),
+                };
+                (
+                    git_ops::add_worktree(&self.repo, oid, &path, &branch),
+                    format!("Added worktree {} on new branch {branch}", path.display()),
+                )
+            }
+        };
+
+        self.notice = Some(match created {
+            Ok(()) => match git_ops::load_refs(&self.repo, &mut self.history.commits) {
+                Ok(()) => format!("{done} at {short_oid}"),
+                Err(err) => format!("{done} at {short_oid}, but refs could not be reloaded: {err}"),
+            },
+            Err(TrekError::Git(err)) => format!("{}: {}", kind.name(), err.message()),
+            Err(err) => format!("{}: {err}", kind.name()),
+        });
+    }
+
+    /// Where a worktree named `path` goes: beside the repository unless the path is absolute,
+    /// so it does not land inside the working tree as untracked files.
+    fn worktree_path(&self, path: &str) -> PathBuf {
+        let root = self.repo.workdir().unwrap_or_else(|| self.repo.path());
+        root.parent().unwrap_or(root).join(path)
+    }
+}
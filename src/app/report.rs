@@ -0,0 +1,139 @@
+use crate::data::RefKind;
+use crate::error::{Result, TrekError};
+use crate::report::ReleaseReport;
+
+use super::App;
+
+/// A release report open in its panel.
+#[derive(Clone, Debug)]
+pub struct ReportPanel {
+    pub report: ReleaseReport,
+    /// The report rendered once, shown in the panel and written on export.
+    pub markdown: String,
+    pub scroll: usize,
+}
+
+impl App {
+    pub fn report(&self) -> Option<&ReportPanel> {
+        self.report.as_ref()
+    }
+
+    /// Open the report for `arg`, reporting a range that cannot be resolved as a notice.
+    pub fn open_report(&mut self, arg: &str) {
+        match self.release_report(arg) {
+            Ok(report) => {
+                self.report = Some(ReportPanel {
+                    markdown: report.to_markdown(),
+                    report,
+                    scroll: 0,
+                });
+            }
+            Err(TrekError::Git(err)) => self.notice = Some(format!("report: {}", err.message())),
+            Err(TrekError::Report(msg)) => self.notice = Some(format!("report: {msg}")),
+            Err(err) => self.notice = Some(format!("report: {err}")),
+        }
+    }
+
+    /// Summarize the range named by `arg`: `<from>..<to>`, `<from> <to>`, or `<from>`
+    /// up to the current commit. Without an argument the range starts at the nearest
+    /// tag older than the current commit.
+    pub fn release_report(&self, arg: &str) -> Result<ReleaseReport> {
+        let arg = arg.trim();
+        let (from, to) = match arg.split_once("..") {
+            Some((from, to)) => (from.trim(), to.trim()),
+            None => arg
+                .split_once(char::is_whitespace)
+                .map_or((arg, ""), |(from, to)| (from, to.trim())),
+        };
+
+        let (to_idx, to_name) = if to.is_empty() {
+            (self.commit_idx, self.report_name(self.commit_idx))
+        } else {
+            (self.report_rev(to)?, to.to_string())
+        };
+        let (from_idx, from_name) = if from.is_empty() {
+            let idx = self
+                .history
+                .commits
+                .iter()
+                .enumerate()
+                .skip(to_idx + 1)
+                .find(|(_, c)| c.is_tagged())
+                .map(|(i, _)| i)
+                .ok_or_else(|| {
+                    TrekError::Report("no older tag to start from; give <from>..<to>".to_string())
+                })?;
+            (idx, self.report_name(idx))
+        } else {
+            (self.report_rev(from)?, from.to_string())
+        };
+
+        // Accept the range either way round; the older commit is where it starts.
+        let (range, names) = if from_idx >= to_idx {
+            ((from_idx, to_idx), (from_name, to_name))
+        } else {
+            ((to_idx, from_idx), (to_name, from_name))
+        };
+        ReleaseReport::build(&self.repo, &self.history, range, names, &self.config.health)
+    }
+
+    /// Index of the loaded commit `rev` names.
+    fn report_rev(&self, rev: &str) -> Result<usize> {
+        let oid = self.repo.revparse_single(rev)?.peel_to_commit()?.id();
+        self.history
+            .commits
+            .iter()
+            .position(|c| c.oid == oid)
+            .ok_or_else(|| {
+                TrekError::Report(format!(
+                    "{rev} is not in the loaded history; raise the limit"
+                ))
+            })
+    }
+
+    /// The first tag on the commit at `idx`, else its sha.
+    fn report_name(&self, idx: usize) -> String {
+        let Some(commit) = self.history.commits.get(idx) else {
+            return String::new();
+        };
+        commit
+            .refs
+            .iter()
+            .find(|r| r.kind == RefKind::Tag)
+            .map_or_else(|| commit.short_oid.clone(), |r| r.name.clone())
+    }
+
+    pub fn close_report(&mut self) {
+        self.report = None;
+    }
+
+    pub fn scroll_report(&mut self, delta: isize) {
+        if let Some(panel) = &mut self.report {
+            panel.scroll = panel.scroll.saturating_add_signed(delta);
+        }
+    }
+
+    /// Write the open report as Markdown next to the working tree.
+    pub fn export_report(&mut self) {
+        let Some(panel) = &self.report else {
+            return;
+        };
+        let name = format!(
+            "release-report-{}..{}.md",
+            panel.report.from, panel.report.to
+        )
+        .replace('/', "-");
+        let path = self
+            .repo
+            .workdir()
+            .unwrap_or_else(|| self.repo.path())
+            .join(name);
+        self.notice = Some(match std::fs::write(&path, &panel.markdown) {
+            Ok(()) => format!("Wrote {}", path.display()),
+            Err(err) => format!("report: could not write {}: {err}", path.display()),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests;
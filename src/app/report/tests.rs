@@ -0,0 +1,39 @@
+use git2::Repository;
+
+use super::*;
+use crate::config::Config;
+use crate::git_ops::testing::{self, commit};
+
+/// Three commits with `v1.0` on the oldest; indices run newest first.
+fn app() -> Result<(tempfile::TempDir, App)> {
+    let (dir, repo) = testing::init()?;
+    let v1 = commit(&repo, &[("a.rs", Some("a\n"))])?;
+    repo.tag_lightweight("v1.0", &repo.find_object(v1, None)?, false)?;
+    commit(&repo, &[("a.rs", Some("a\nb\n"))])?;
+    commit(&repo, &[("b.rs", Some("b\n"))])?;
+    let repo = Repository::open(dir.path())?;
+    Ok((dir, App::with_config(repo, Config::default())?))
+}
+
+#[test]
+fn release_report_accepts_the_range_either_way_round() -> Result<()> {
+    let (_dir, app) = app()?;
+    for arg in ["v1.0..HEAD", "HEAD..v1.0", "HEAD v1.0"] {
+        let report = app.release_report(arg)?;
+        assert_eq!((report.from.as_str(), report.commits), ("v1.0", 2), "{arg}");
+        assert_eq!(report.to, "HEAD", "{arg}");
+    }
+    Ok(())
+}
+
+#[test]
+fn release_report_starts_at_the_nearest_older_tag_by_default() -> Result<()> {
+    let (_dir, mut app) = app()?;
+    let report = app.release_report("")?;
+    assert_eq!((report.from.as_str(), report.commits), ("v1.0", 2));
+
+    app.commit_idx = 2;
+    let err = app.release_report("");
+    assert!(matches!(err, Err(TrekError::Report(msg)) if msg.starts_with("no older tag")));
+    Ok(())
+}
@@ -0,0 +1,365 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::{Result, TrekError};
+use crate::git_ops::{self, CheckoutPlan, PathChange, RestorePlan, RestoreTarget, RestoreUndo};
+
+use super::App;
+
+/// What a restore acts on: one file, or a set of files and directories.
+#[derive(Clone, Debug)]
+pub enum RestoreScope {
+    File(RestorePlan),
+    Paths(CheckoutPlan),
+}
+
+impl RestoreScope {
+    fn short_oid(&self) -> String {
+        match self {
+            Self::File(plan) => plan.short_oid(),
+            Self::Paths(plan) => plan.short_oid(),
+        }
+    }
+
+    /// Paths that exist at the commit, and so can be copied out of it.
+    fn present_paths(&self) -> Vec<String> {
+        match self {
+            Self::File(plan) => vec![plan.path.clone()],
+            Self::Paths(plan) => plan
+                .changes
+                .iter()
+                .filter(|(_, change)| *change != PathChange::Deleted)
+                .map(|(path, _)| path.clone())
+                .collect(),
+        }
+    }
+}
+
+/// A restore waiting for confirmation, with where it will write.
+#[derive(Clone, Debug)]
+pub struct RestorePrompt {
+    pub scope: RestoreScope,
+    pub target: RestoreTarget,
+    /// Destination typed for [`RestoreTarget::Path`]: a file for one file, else a directory.
+    pub dest: String,
+}
+
+impl RestorePrompt {
+    pub fn is_single_file(&self) -> bool {
+        matches!(self.scope, RestoreScope::File(_))
+    }
+
+    /// The typed destination made absolute against the current directory, as
+    /// `--restore-path` is.
+    pub fn resolved_dest(&self) -> Option<PathBuf> {
+        let dest = self.dest.trim();
+        if dest.is_empty() {
+            return None;
+        }
+        std::path::absolute(dest).ok()
+    }
+}
+
+impl App {
+    /// Restore waiting for confirmation.
+    pub fn restore_prompt(&self) -> Option<&RestorePrompt> {
+        self.restore_prompt.as_ref()
+    }
+
+    /// Content restored to stdout, printed once the terminal is released.
+    pub fn stdout_output(&self) -> &[u8] {
+        &self.stdout_output
+    }
+
+    /// Work out what restoring the selected paths would change and ask for confirmation.
+    pub fn restore_selected(&mut self) {
+        let paths = self.restore_paths();
+        if paths.is_empty() {
+            return;
+        }
+        if let [path] = paths.as_slice() {
+            if !path.ends_with('/') {
+                self.restore_file_at(path, self.commit_idx);
+                return;
+            }
+        }
+        if self.refuse_bare() {
+            return;
+        }
+        let Some(commit) = self.current_commit() else {
+            return;
+        };
+
+        let plan = match git_ops::plan_checkout(&self.repo, commit.oid, &paths) {
+            Ok(plan) => plan,
+            Err(err) => {
+                self.restore_failed(&err);
+                return;
+            }
+        };
+        if plan.changes.is_empty() {
+            self.notice = Some(format!(
+                "The selection already matches {}",
+                commit.short_oid
+            ));
+            return;
+        }
+        self.prompt_restore(RestoreScope::Paths(plan));
+    }
+
+    /// Ask to restore `path` as it was at the commit at `commit_idx`.
+    pub(super) fn restore_file_at(&mut self, path: &str, commit_idx: usize) {
+        if self.refuse_bare() {
+            return;
+        }
+        let Some(commit) = self.history.commits.get(commit_idx) else {
+            return;
+        };
+        match git_ops::plan_restore(&self.repo, commit.oid, path) {
+            Ok(plan) => self.prompt_restore(RestoreScope::File(plan)),
+            Err(TrekError::Git(err)) if err.code() == git2::ErrorCode::NotFound => {
+                self.notice = Some(format!("{path} is not present at {}", commit.short_oid));
+            }
+            Err(err) => self.restore_failed(&err),
+        }
+    }
+
+    /// Report a restore that could not be planned or carried out as a notice.
+    fn restore_failed(&mut self, err: &TrekError) {
+        self.notice = Some(match err {
+            TrekError::Git(err) => format!("restore: {}", err.message()),
+            err => format!("restore: {err}"),
+        });
+    }
+
+    fn refuse_bare(&mut self) -> bool {
+        if self.repo.is_bare() {
+            self.notice = Some("Restore needs a working tree; this repository is bare".to_string());
+        }
+        self.repo.is_bare()
+    }
+
+    /// Open the confirmation for `scope` with the configured target and destination.
+    fn prompt_restore(&mut self, scope: RestoreScope) {
+        let target = match (&scope, self.config.restore_target) {
+            (RestoreScope::Paths(_), RestoreTarget::Stdout) => RestoreTarget::Worktree,
+            (_, target) => target,
+        };
+        let dest = match (&self.config.restore_path, &scope) {
+            (Some(path), _) => path.display().to_string(),
+            (None, RestoreScope::File(plan)) => {
+                self.default_dest(&format!("{}.{}", plan.path, plan.short_oid()))
+            }
+            (None, RestoreScope::Paths(plan)) => {
+                self.default_dest(&format!("restored-{}/", plan.short_oid()))
+            }
+        };
+        self.restore_prompt = Some(RestorePrompt {
+            scope,
+            target,
+            dest,
+        });
+    }
+
+    /// `name` in the working tree, written relative to the current directory when inside it.
+    fn default_dest(&self, name: &str) -> String {
+        let path = self
+            .repo
+            .workdir()
+            .unwrap_or_else(|| self.repo.path())
+            .join(name);
+        let cwd = std::env::current_dir().unwrap_or_default();
+        let shown = path
+            .strip_prefix(&cwd)
+            .unwrap_or(&path)
+            .display()
+            .to_string();
+        // `join` drops the trailing slash that marks a directory.
+        if name.ends_with('/') && !shown.ends_with('/') {
+            format!("{shown}/")
+        } else {
+            shown
+        }
+    }
+
+    pub fn cancel_restore(&mut self) {
+        self.restore_prompt = None;
+    }
+
+    /// Switch the pending restore to the next target.
+    pub fn cycle_restore_target(&mut self) {
+        if let Some(prompt) = &mut self.restore_prompt {
+            prompt.target = prompt.target.next(prompt.is_single_file());
+        }
+    }
+
+    pub fn restore_dest_input(&mut self, c: char) {
+        if let Some(prompt) = &mut self.restore_prompt {
+            prompt.dest.push(c);
+        }
+    }
+
+    pub fn restore_dest_backspace(&mut self) {
+        if let Some(prompt) = &mut self.restore_prompt {
+            prompt.dest.pop();
+        }
+    }
+
+    /// Carry out the confirmed restore, backing up and remembering what it replaces.
+    pub fn confirm_restore(&mut self) {
+        let Some(prompt) = self.restore_prompt.take() else {
+            return;
+        };
+        if prompt.target == RestoreTarget::Worktree
+            && matches!(&prompt.scope, RestoreScope::File(plan) if plan.is_noop())
+        {
+            self.notice = Some("The working copy already matches".to_string());
+            return;
+        }
+        if prompt.target == RestoreTarget::Path && prompt.dest.trim().is_empty() {
+            self.notice = Some("Type a destination path".to_string());
+            self.restore_prompt = Some(prompt);
+            return;
+        }
+
+        let restored = match &prompt.scope {
+            RestoreScope::File(plan) => {
+                format!("{} from {}", plan.path, plan.short_oid())
+            }
+            RestoreScope::Paths(plan) => {
+                format!("{} files from {}", plan.changes.len(), plan.short_oid())
+            }
+        };
+        let undo = match self.apply_restore_prompt(&prompt) {
+            Ok(undo) => undo,
+            Err(err) => {
+                // Keep the prompt so another target or destination can be tried.
+                self.restore_failed(&err);
+                self.restore_prompt = Some(prompt);
+                return;
+            }
+        };
+        let Some(undo) = undo else {
+            let done = format!("Printed {restored} to stdout");
+            self.notice = Some(format!("{done} on exit"));
+            self.message = Some(done);
+            return;
+        };
+
+        let restored = match (&prompt.scope, prompt.target) {
+            (RestoreScope::Paths(plan), RestoreTarget::Worktree) => {
+                format!("Restored {restored} ({})", plan.summary())
+            }
+            (_, RestoreTarget::Worktree) => format!("Restored {restored}"),
+            (_, RestoreTarget::Index) => format!("Staged {restored}"),
+            (_, _) => match undo.files.as_slice() {
+                [file] => format!("Copied {restored} to {}", self.display_path(&file.target)),
+                _ => format!("Copied {restored}"),
+            },
+        };
+        self.notice = Some(match &undo.backup {
+            Some(backup) => format!("{restored}, backup in {}", self.display_path(backup)),
+            None => restored.clone(),
+        });
+        self.message = Some(restored);
+        self.restore_undo.push(undo);
+    }
+
+    /// Write the prompt's versions to its target; `None` when they go to stdout.
+    fn apply_restore_prompt(&mut self, prompt: &RestorePrompt) -> Result<Option<RestoreUndo>> {
+        let (oid, label) = match &prompt.scope {
+            RestoreScope::File(plan) => (plan.oid, plan.path.clone()),
+            RestoreScope::Paths(plan) => (plan.oid, format!("{} files", plan.changes.len())),
+        };
+        let workdir = self
+            .repo
+            .workdir()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        let undo = match prompt.target {
+            RestoreTarget::Worktree => match &prompt.scope {
+                RestoreScope::File(plan) => git_ops::apply_restore(&self.repo, plan)?,
+                RestoreScope::Paths(plan) => git_ops::apply_checkout(&self.repo, plan)?,
+            },
+            RestoreTarget::Index => {
+                let paths = match &prompt.scope {
+                    RestoreScope::File(plan) => vec![plan.path.clone()],
+                    RestoreScope::Paths(plan) => {
+                        plan.changes.iter().map(|(path, _)| path.clone()).collect()
+                    }
+                };
+                git_ops::stage_files(&self.repo, oid, &paths, label)?
+            }
+            RestoreTarget::Side => {
+                let short = prompt.scope.short_oid();
+                let copies: Vec<(String, PathBuf)> = prompt
+                    .scope
+                    .present_paths()
+                    .into_iter()
+                    .map(|path| {
+                        let dest = workdir.join(format!("{path}.{short}"));
+                        (path, dest)
+                    })
+                    .collect();
+                git_ops::write_copies(&self.repo, oid, &copies, label)?
+            }
+            RestoreTarget::Path => {
+                let dest = std::path::absolute(prompt.dest.trim())?;
+                let copies: Vec<(String, PathBuf)> = match &prompt.scope {
+                    RestoreScope::File(plan) => vec![(plan.path.clone(), dest)],
+                    RestoreScope::Paths(_) => prompt
+                        .scope
+                        .present_paths()
+                        .into_iter()
+                        .map(|path| {
+                            let file = dest.join(&path);
+                            (path, file)
+                        })
+                        .collect(),
+                };
+                git_ops::write_copies(&self.repo, oid, &copies, label)?
+            }
+            RestoreTarget::Stdout => {
+                for path in prompt.scope.present_paths() {
+                    let bytes = git_ops::file_bytes(&self.repo, oid, &path)?;
+                    self.stdout_output.extend_from_slice(&bytes);
+                }
+                return Ok(None);
+            }
+        };
+        Ok(Some(undo))
+    }
+
+    /// Whether there is a restore from this session to undo.
+    pub fn can_undo_restore(&self) -> bool {
+        !self.restore_undo.is_empty()
+    }
+
+    /// Put back the working copy content replaced by the most recent restore.
+    pub fn undo_restore(&mut self) {
+        let Some(undo) = self.restore_undo.pop() else {
+            self.notice = Some("Nothing to undo".to_string());
+            return;
+        };
+
+        if let Err(err) = git_ops::undo_restore(&self.repo, &undo) {
+            self.restore_failed(&err);
+            // Leave it on the stack so the undo can be retried.
+            self.restore_undo.push(undo);
+            return;
+        }
+        let undone = format!("Undid restore of {}", undo.label);
+        self.notice = Some(undone.clone());
+        self.message = Some(undone);
+    }
+
+    /// `path` relative to the working tree when it lies inside it.
+    fn display_path(&self, path: &Path) -> String {
+        self.repo
+            .workdir()
+            .and_then(|dir| path.strip_prefix(dir).ok())
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+}
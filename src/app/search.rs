@@ -0,0 +1,100 @@
+use super::App;
+
+/// State of a commit search: the query and the indices of matching commits.
+#[derive(Clone, Debug, Default)]
+pub struct CommitSearch {
+    pub query: String,
+    pub matches: Vec<usize>,
+    /// True while the query is still being typed.
+    pub editing: bool,
+}
+
+impl App {
+    pub fn commit_search(&self) -> Option<&CommitSearch> {
+        self.commit_search.as_ref()
+    }
+
+    /// Commit indices matched by the active commit search, pickaxe search or line trace.
+    pub fn search_matches(&self) -> &[usize] {
+        if let Some(search) = &self.commit_search {
+            return &search.matches;
+        }
+        if let Some(trace) = &self.line_trace {
+            return &trace.commits;
+        }
+        self.pickaxe.as_ref().map_or(&[], |p| p.commits.as_slice())
+    }
+
+    pub fn open_commit_search(&mut self) {
+        self.pickaxe = None;
+        self.line_trace = None;
+        self.commit_search = Some(CommitSearch {
+            editing: true,
+            ..CommitSearch::default()
+        });
+    }
+
+    pub fn clear_commit_search(&mut self) {
+        self.commit_search = None;
+    }
+
+    pub fn search_input(&mut self, c: char) {
+        if let Some(search) = &mut self.commit_search {
+            search.query.push(c);
+        }
+    }
+
+    pub fn search_backspace(&mut self) {
+        if let Some(search) = &mut self.commit_search {
+            search.query.pop();
+        }
+    }
+
+    /// Finish typing the query, collect matches and jump to the first one.
+    pub fn search_confirm(&mut self) {
+        let Some(search) = &mut self.commit_search else {
+            return;
+        };
+        search.editing = false;
+        search.matches = self
+            .history
+            .commits
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.matches(&search.query))
+            .map(|(i, _)| i)
+            .collect();
+
+        if !search.matches.contains(&self.commit_idx) {
+            self.jump_to_match(true);
+        }
+    }
+
+    /// Move to the next older (`forward`) or newer match, wrapping around.
+    pub fn jump_to_match(&mut self, forward: bool) {
+        let matches = self.search_matches();
+        let current = self.commit_idx;
+        let target = if forward {
+            matches
+                .iter()
+                .find(|&&i| i > current)
+                .or_else(|| matches.first())
+        } else {
+            matches
+                .iter()
+                .rev()
+                .find(|&&i| i < current)
+                .or_else(|| matches.last())
+        };
+        if let Some(&idx) = target {
+            self.commit_idx = idx;
+        }
+    }
+
+    /// Position of the current commit among the matches, as `(n, total)`.
+    pub fn search_position(&self) -> Option<(usize, usize)> {
+        let matches = self.search_matches();
+        let n = matches.iter().position(|&i| i == self.commit_idx)?;
+        Some((n + 1, matches.len()))
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use crate::views::{Aggregate, SortMode, Zoom};
+
+use super::App;
+
+impl App {
+    pub fn seismic_scroll(&self) -> usize {
+        self.seismic_scroll
+    }
+
+    pub fn seismic_scroll_vertical(&mut self, delta: i32) {
+        self.seismic_reveal = None;
+        let amount = delta.unsigned_abs() as usize;
+        if delta > 0 {
+            self.seismic_scroll = self.seismic_scroll.saturating_add(amount);
+        } else {
+            self.seismic_scroll = self.seismic_scroll.saturating_sub(amount);
+        }
+    }
+
+    pub fn seismic_filter_inactive(&self) -> bool {
+        self.seismic_filter_inactive
+    }
+
+    pub fn toggle_seismic_filter(&mut self) {
+        self.seismic_filter_inactive = !self.seismic_filter_inactive;
+        self.seismic_scroll = 0;
+    }
+
+    pub fn seismic_zoom(&self) -> Zoom {
+        self.seismic_zoom
+    }
+
+    pub fn seismic_aggregate(&self) -> Aggregate {
+        self.seismic_aggregate
+    }
+
+    pub fn zoom_seismic_in(&mut self) {
+        self.seismic_zoom = self.seismic_zoom.zoom_in();
+    }
+
+    pub fn zoom_seismic_out(&mut self) {
+        self.seismic_zoom = self.seismic_zoom.zoom_out();
+    }
+
+    pub fn toggle_seismic_aggregate(&mut self) {
+        self.seismic_aggregate = self.seismic_aggregate.toggle();
+    }
+
+    pub fn seismic_sort(&self) -> SortMode {
+        self.seismic_sort
+    }
+
+    pub fn cycle_seismic_sort(&mut self) {
+        self.seismic_sort = self.seismic_sort.next();
+        self.seismic_scroll = 0;
+    }
+
+    pub fn seismic_grouped(&self) -> bool {
+        self.seismic_grouped
+    }
+
+    pub fn toggle_seismic_grouping(&mut self) {
+        self.seismic_grouped = !self.seismic_grouped;
+        self.seismic_scroll = 0;
+    }
+
+    pub fn seismic_collapsed(&self) -> &HashSet<String> {
+        &self.seismic_collapsed
+    }
+
+    pub fn toggle_seismic_dir(&mut self, dir: &str) {
+        if !self.seismic_collapsed.remove(dir) {
+            self.seismic_collapsed.insert(dir.to_string());
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::error::Result;
+use crate::mouse::HitTarget;
+
+use super::App;
+
+impl App {
+    /// Files and directories picked for a multi-path restore, besides the selected file.
+    pub fn selection(&self) -> impl Iterator<Item = &str> {
+        self.selection.iter().map(String::as_str)
+    }
+
+    /// Whether `path` is picked, directly or through a picked directory.
+    pub fn is_path_selected(&self, path: &str) -> bool {
+        self.selection
+            .iter()
+            .any(|s| s == path || s == "./" || (s.ends_with('/') && path.starts_with(s.as_str())))
+    }
+
+    /// Everything a restore would act on: the picked paths and the selected file,
+    /// leaving out paths already covered by a picked directory.
+    pub fn restore_paths(&self) -> Vec<String> {
+        let mut paths: Vec<String> = self.selection.iter().cloned().collect();
+        if let Some(file) = &self.selected_file {
+            if !paths.contains(file) {
+                paths.push(file.clone());
+            }
+        }
+        let covered = |path: &str| {
+            self.selection.iter().any(|s| {
+                s != path && s.ends_with('/') && (s == "./" || path.starts_with(s.as_str()))
+            })
+        };
+        paths.retain(|p| !covered(p));
+        paths.sort();
+        paths
+    }
+
+    /// Add `path` to the restore set, or take it out if it is already there.
+    pub fn toggle_selected_path(&mut self, path: String) {
+        if self.selection.remove(&path) {
+            return;
+        }
+        if self.selected_file.as_ref() == Some(&path) {
+            self.selected_file = None;
+            return;
+        }
+        self.selection.insert(path);
+    }
+
+    /// Toggle the focused file or directory, else the selected file.
+    pub fn toggle_select(&mut self) {
+        let Some(path) = self.focused_row().or_else(|| self.selected_file.clone()) else {
+            self.notice = Some("Focus a file or directory to select it".to_string());
+            return;
+        };
+        self.toggle_selected_path(path);
+    }
+
+    /// Select the directory of the focused or selected file; repeating widens to its parent.
+    pub fn select_parent_dir(&mut self) {
+        let Some(path) = self.focused_row().or_else(|| self.selected_file.clone()) else {
+            self.notice = Some("Select a file first".to_string());
+            return;
+        };
+        let trimmed = path.trim_end_matches('/');
+        let ancestors = trimmed
+            .match_indices('/')
+            .rev()
+            .map(|(i, _)| trimmed.get(..=i).unwrap_or_default().to_string());
+        let Some(dir) = ancestors
+            .into_iter()
+            .find(|dir| !self.is_path_selected(dir))
+        else {
+            self.notice = Some("No wider directory to select".to_string());
+            return;
+        };
+
+        self.selection.retain(|s| !s.starts_with(dir.as_str()));
+        self.notice = Some(format!("Selected {dir}"));
+        self.selection.insert(dir);
+    }
+
+    /// Shift-click: files and directory rows toggle in the selection, anything else clicks normally.
+    pub fn handle_shift_click(&mut self, target: HitTarget) -> Result<()> {
+        match target {
+            HitTarget::File(path) | HitTarget::SeismicDir(path) => {
+                self.toggle_selected_path(path);
+                Ok(())
+            }
+            other => self.handle_click(other),
+        }
+    }
+}
@@ -1,8 +1,19 @@
-use git2::Repository;
+use std::collections::{BTreeSet, HashSet};
 
+use git2::{Oid, Repository};
+
+use crate::config::Config;
 use crate::data::History;
-use crate::mouse::MouseState;
-use crate::views::ViewMode;
+use crate::git_ops::RestoreUndo;
+use crate::keymap::KeyChord;
+use crate::marks::Marks;
+use crate::mouse::{HitBox, MouseState};
+use crate::views::{Aggregate, SortMode, ViewMode, Zoom};
+
+use super::{
+    CommitSearch, ExternalOpen, Finder, Graveyard, LineTrace, MarkPrompt, Palette, Pickaxe,
+    Playback, RefPrompt, ReportPanel, RestorePrompt, Viewer,
+};
 
 pub struct App {
     pub repo: Repository,
@@ -10,9 +21,54 @@ pub struct App {
     pub view: ViewMode,
     pub commit_idx: usize,
     pub selected_file: Option<String>,
+    /// Extra files and directories (ending in `/`) picked for restore.
+    pub selection: BTreeSet<String>,
     pub mouse: MouseState,
     pub should_quit: bool,
     pub message: Option<String>,
     pub seismic_scroll: usize,
     pub seismic_filter_inactive: bool,
+    pub seismic_zoom: Zoom,
+    pub seismic_aggregate: Aggregate,
+    pub seismic_sort: SortMode,
+    pub seismic_grouped: bool,
+    pub seismic_collapsed: HashSet<String>,
+    pub seismic_reveal: Option<String>,
+    pub finder: Option<Finder>,
+    pub graveyard: Option<Graveyard>,
+    pub commit_search: Option<CommitSearch>,
+    pub pickaxe: Option<Pickaxe>,
+    /// Keys typed so far of a multi-key sequence.
+    pub pending_keys: Vec<KeyChord>,
+    pub palette: Option<Palette>,
+    pub notice: Option<String>,
+    /// Path prefix that files must start with to be shown.
+    pub path_filter: Option<String>,
+    /// Commit pinned as the base of a two-commit comparison.
+    pub compare_base: Option<Oid>,
+    pub playback: Playback,
+    pub marks: Marks,
+    pub mark_prompt: Option<MarkPrompt>,
+    /// Element holding the keyboard focus, as drawn in the last frame.
+    pub focus: Option<HitBox>,
+    /// Elements the focus can move between in the last frame.
+    pub nav_boxes: Vec<HitBox>,
+    /// Seismic row paths of the last frame, in display order.
+    pub layout_rows: Vec<String>,
+    /// Scroll offset of the help overlay while it is open.
+    pub help_scroll: Option<usize>,
+    /// Restore waiting for the user to confirm it.
+    pub restore_prompt: Option<RestorePrompt>,
+    /// Content replaced by this session's restores, most recent last.
+    pub restore_undo: Vec<RestoreUndo>,
+    /// Content restored to stdout, printed on exit.
+    pub stdout_output: Vec<u8>,
+    /// File version to open in an outside program before the next frame.
+    pub external: Option<ExternalOpen>,
+    pub viewer: Option<Viewer>,
+    pub line_trace: Option<LineTrace>,
+    /// Name being typed for a branch, tag or worktree to create.
+    pub ref_prompt: Option<RefPrompt>,
+    pub report: Option<ReportPanel>,
+    pub config: Config,
 }
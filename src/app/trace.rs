@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use crate::error::Result;
+use crate::git_ops::{self, LineRange};
+
+use super::App;
+
+/// History of a line range in one file, like `git log -L`.
+#[derive(Clone, Debug)]
+pub struct LineTrace {
+    pub path: String,
+    /// What was traced, such as `lines 10-24` or `fn parse`.
+    pub label: String,
+    /// Where the range sat at each commit walked, by commit index.
+    pub ranges: BTreeMap<usize, LineRange>,
+    /// Commits that changed the range, newest first.
+    pub commits: Vec<usize>,
+}
+
+impl LineTrace {
+    /// Whether `path` had lines of the range changed at `commit_idx`.
+    pub fn is_hit(&self, path: &str, commit_idx: usize) -> bool {
+        self.path == path && self.commits.contains(&commit_idx)
+    }
+
+    /// The range at `commit_idx` when it is in `path`.
+    pub fn range_in(&self, path: &str, commit_idx: usize) -> Option<LineRange> {
+        (self.path == path)
+            .then(|| self.ranges.get(&commit_idx).copied())
+            .flatten()
+    }
+}
+
+impl App {
+    pub fn line_trace(&self) -> Option<&LineTrace> {
+        self.line_trace.as_ref()
+    }
+
+    /// Trace the selected file's lines named by `arg`: `<start>,<end>`, a single line,
+    /// or a function name (optionally written `:name`). Without an argument, the lines
+    /// selected in the content viewer are traced.
+    pub fn trace_lines_from(&mut self, arg: &str) -> Result<()> {
+        let Some(path) = self.selected_file.clone() else {
+            self.notice = Some("Select a file to trace lines in".to_string());
+            return Ok(());
+        };
+        let (range, label) = if arg.is_empty() {
+            let Some(range) = self.viewer_selection() else {
+                self.notice = Some(
+                    "trace <start>,<end> or trace :<function>, or pick lines in the viewer"
+                        .to_string(),
+                );
+                return Ok(());
+            };
+            (range, line_label(range))
+        } else if let Some(range) = parse_range(arg) {
+            (range, line_label(range))
+        } else {
+            let name = arg.strip_prefix(':').unwrap_or(arg);
+            let Some(commit) = self.current_commit() else {
+                return Ok(());
+            };
+            let lines = git_ops::load_content(&self.repo, commit.oid, None, &path)?
+                .map(|c| c.lines)
+                .unwrap_or_default();
+            let Some(range) = git_ops::find_function(&lines, name) else {
+                self.notice = Some(format!("trace: no definition of '{name}' in {path}"));
+                return Ok(());
+            };
+            (range, name.to_string())
+        };
+        self.trace(path, range, label)
+    }
+
+    fn trace(&mut self, path: String, range: LineRange, label: String) -> Result<()> {
+        let oids: Vec<_> = self.history.commits.iter().map(|c| c.oid).collect();
+        let result = git_ops::trace_lines(&self.repo, &oids, self.commit_idx, &path, range)?;
+        if result.commits.is_empty() {
+            self.notice = Some(format!("No loaded commit changed {label} of {path}"));
+            return Ok(());
+        }
+
+        self.commit_search = None;
+        self.pickaxe = None;
+        let touched_here = result.commits.contains(&self.commit_idx);
+        self.line_trace = Some(LineTrace {
+            path,
+            label,
+            ranges: result.ranges,
+            commits: result.commits,
+        });
+        if !touched_here {
+            self.jump_to_match(true);
+        }
+        Ok(())
+    }
+}
+
+/// `10,24`, `10-24` or `10`, with the ends in either order.
+fn parse_range(arg: &str) -> Option<LineRange> {
+    let (start, end) = arg.split_once([',', '-', ':']).unwrap_or((arg, arg));
+    let start: usize = start.trim().parse().ok()?;
+    let end: usize = end.trim().parse().ok()?;
+    (start > 0 && end > 0).then_some((start.min(end), start.max(end)))
+}
+
+fn line_label((start, end): LineRange) -> String {
+    if start == end {
+        format!("line {start}")
+    } else {
+        format!("lines {start}-{end}")
+    }
+}
@@ -0,0 +1,155 @@
+use git2::Oid;
+
+use crate::error::Result;
+use crate::git_ops::{self, FileContent};
+
+use super::App;
+
+/// State of the file content viewer.
+#[derive(Clone, Debug, Default)]
+pub struct Viewer {
+    /// First line shown; kept while stepping through commits.
+    pub scroll: usize,
+    /// Zero-based line under the cursor.
+    pub cursor: usize,
+    /// Other end of a line selection started at this line.
+    pub anchor: Option<usize>,
+    /// Lines that fit on screen in the last frame.
+    pub height: usize,
+    pub loaded: Option<LoadedContent>,
+}
+
+impl Viewer {
+    fn line_count(&self) -> usize {
+        self.loaded
+            .as_ref()
+            .and_then(|l| l.content.as_ref())
+            .map_or(0, |c| c.lines.len())
+    }
+
+    fn keep_cursor_visible(&mut self) {
+        if self.cursor < self.scroll {
+            self.scroll = self.cursor;
+        } else if self.height > 0 && self.cursor >= self.scroll + self.height {
+            self.scroll = self.cursor + 1 - self.height;
+        }
+    }
+
+    /// Selected lines, one-based: the cursor line, or from the anchor to the cursor.
+    pub fn selection(&self) -> (usize, usize) {
+        let anchor = self.anchor.unwrap_or(self.cursor);
+        (anchor.min(self.cursor) + 1, anchor.max(self.cursor) + 1)
+    }
+}
+
+/// Content the viewer shows, and the file and commits it was loaded for.
+#[derive(Clone, Debug)]
+pub struct LoadedContent {
+    pub path: String,
+    pub oid: Oid,
+    pub base: Option<Oid>,
+    /// `None` when the file does not exist at the commit.
+    pub content: Option<FileContent>,
+}
+
+impl App {
+    pub fn viewer(&self) -> Option<&Viewer> {
+        self.viewer.as_ref()
+    }
+
+    /// Show the selected file's content at the current commit.
+    pub fn open_viewer(&mut self) -> Result<()> {
+        if self.selected_file.is_none() {
+            self.notice = Some("Select a file to show".to_string());
+            return Ok(());
+        }
+        self.viewer = Some(Viewer::default());
+        self.refresh_viewer()
+    }
+
+    pub fn close_viewer(&mut self) {
+        self.viewer = None;
+    }
+
+    pub fn move_viewer_cursor(&mut self, delta: isize) {
+        let Some(viewer) = &mut self.viewer else {
+            return;
+        };
+        viewer.cursor = viewer
+            .cursor
+            .saturating_add_signed(delta)
+            .min(viewer.line_count().saturating_sub(1));
+        viewer.keep_cursor_visible();
+    }
+
+    /// Start a line selection at the cursor, or drop the current one.
+    pub fn toggle_viewer_anchor(&mut self) {
+        if let Some(viewer) = &mut self.viewer {
+            viewer.anchor = match viewer.anchor {
+                Some(_) => None,
+                None => Some(viewer.cursor),
+            };
+        }
+    }
+
+    /// Lines picked in the viewer, when it shows the selected file.
+    pub fn viewer_selection(&self) -> Option<(usize, usize)> {
+        let viewer = self.viewer.as_ref()?;
+        (viewer.line_count() > 0).then(|| viewer.selection())
+    }
+
+    /// Remember how many lines the viewer showed, keeping the cursor on screen.
+    pub fn record_viewer_height(&mut self, height: usize) {
+        if let Some(viewer) = &mut self.viewer {
+            viewer.height = height;
+            viewer.keep_cursor_visible();
+        }
+    }
+
+    /// Reload the content when the selected file, commit or compare base has changed.
+    pub fn refresh_viewer(&mut self) -> Result<()> {
+        let Some(path) = self.selected_file.clone() else {
+            self.viewer = None;
+            return Ok(());
+        };
+        let Some(oid) = self.current_commit().map(|c| c.oid) else {
+            return Ok(());
+        };
+        let base = self
+            .prev_commit_idx()
+            .and_then(|i| self.history.commits.get(i))
+            .map(|c| c.oid);
+        let Some(viewer) = &mut self.viewer else {
+            return Ok(());
+        };
+        let current = viewer
+            .loaded
+            .as_ref()
+            .is_some_and(|l| l.path == path && l.oid == oid && l.base == base);
+        if current {
+            return Ok(());
+        }
+
+        let content = git_ops::load_content(&self.repo, oid, base, &path)?;
+        // While tracing lines of this file, follow the range as it moves.
+        if let Some((start, end)) = self
+            .line_trace
+            .as_ref()
+            .and_then(|t| t.range_in(&path, self.commit_idx))
+        {
+            if start <= viewer.scroll || end > viewer.scroll + viewer.height {
+                viewer.scroll = start.saturating_sub(3);
+            }
+            viewer.cursor = start.saturating_sub(1);
+        }
+        viewer.loaded = Some(LoadedContent {
+            path,
+            oid,
+            base,
+            content,
+        });
+        viewer.cursor = viewer.cursor.min(viewer.line_count().saturating_sub(1));
+        viewer.keep_cursor_visible();
+        Ok(())
+    }
+}
@@ -1,11 +1,29 @@
+use std::path::PathBuf;
+
 use clap::Parser;
 
 #[derive(Parser, Debug, Clone)]
 #[command(name = "git-trek", about = "Navigate git history visually", version)]
 pub struct Cli {
-    /// Maximum number of commits to load
-    #[arg(long, short, default_value_t = 200)]
-    pub limit: usize,
+    /// Maximum number of commits to load [default: 200]
+    #[arg(long, short)]
+    pub limit: Option<usize>,
+
+    /// View to open on startup (terrain, seismic, strata, flow, stars, surgery)
+    #[arg(long)]
+    pub view: Option<String>,
+
+    /// Where restores write by default (worktree, index, side, path, stdout)
+    #[arg(long, value_name = "TARGET")]
+    pub restore_to: Option<String>,
+
+    /// Destination for restores to a chosen path; implies --restore-to path
+    #[arg(long, value_name = "PATH")]
+    pub restore_path: Option<PathBuf>,
+
+    /// Print a Markdown summary of the changes between two revisions and exit
+    #[arg(long, value_name = "FROM..TO")]
+    pub report: Option<String>,
 
     /// Run initialization check without starting TUI
     #[arg(long, hide = true)]
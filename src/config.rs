@@ -0,0 +1,177 @@
+mod colors;
+mod depth;
+mod file;
+mod playback;
+
+use std::path::{Path, PathBuf};
+
+use git2::Repository;
+
+use crate::cli::Cli;
+use crate::error::{Result, TrekError};
+use crate::git_ops::RestoreTarget;
+use crate::keymap::Keymap;
+use crate::views::ViewMode;
+
+use file::ConfigFile;
+
+pub use colors::{SeismicColors, TerrainColors, Theme};
+pub use depth::ColorDepth;
+pub use playback::{Pace, PlaybackConfig, StopAt};
+
+/// Number of commits loaded when neither the CLI nor a config file sets a limit.
+pub const DEFAULT_LIMIT: usize = 200;
+
+/// Line-count ratios that separate the `Health` classes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HealthThresholds {
+    pub trauma: f64,
+    pub shrank: f64,
+    pub grew: f64,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        Self {
+            trauma: 0.7,
+            shrank: 0.95,
+            grew: 1.05,
+        }
+    }
+}
+
+/// Absolute line deltas that separate the seismic `Intensity` levels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntensityThresholds {
+    pub tremor: usize,
+    pub quake: usize,
+    pub major: usize,
+}
+
+impl Default for IntensityThresholds {
+    fn default() -> Self {
+        Self {
+            tremor: 10,
+            quake: 50,
+            major: 200,
+        }
+    }
+}
+
+/// Resolved user configuration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub limit: usize,
+    pub default_view: ViewMode,
+    pub health: HealthThresholds,
+    pub intensity: IntensityThresholds,
+    pub playback: PlaybackConfig,
+    pub terrain_colors: TerrainColors,
+    pub seismic_colors: SeismicColors,
+    /// Set from the config file, otherwise detected from the environment.
+    pub color_depth: ColorDepth,
+    pub keymap: Keymap,
+    /// Target preselected in the restore dialog.
+    pub restore_target: RestoreTarget,
+    /// Destination prefilled for restores to a chosen path.
+    pub restore_path: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            limit: DEFAULT_LIMIT,
+            default_view: ViewMode::default(),
+            health: HealthThresholds::default(),
+            intensity: IntensityThresholds::default(),
+            playback: PlaybackConfig::default(),
+            terrain_colors: TerrainColors::default(),
+            seismic_colors: SeismicColors::default(),
+            color_depth: ColorDepth::default(),
+            keymap: Keymap::default(),
+            restore_target: RestoreTarget::default(),
+            restore_path: None,
+        }
+    }
+}
+
+impl Config {
+    /// Load the user config, then the repository's `.git-trek.toml`, then apply CLI flags.
+    pub fn load(repo: &Repository, cli: &Cli) -> Result<Self> {
+        let mut config = Self {
+            color_depth: ColorDepth::detect(),
+            ..Self::default()
+        };
+
+        let repo_file = repo.workdir().map(|dir| dir.join(".git-trek.toml"));
+        for path in [user_config_path(), repo_file].into_iter().flatten() {
+            if path.is_file() {
+                config.apply_file(&path)?;
+            }
+        }
+
+        if let Some(limit) = cli.limit {
+            config.limit = limit;
+        }
+        if let Some(view) = &cli.view {
+            config.default_view = ViewMode::from_name(view)
+                .ok_or_else(|| TrekError::Config(format!("unknown view '{view}'")))?;
+        }
+
+        if let Some(path) = &cli.restore_path {
+            config.restore_target = RestoreTarget::Path;
+            config.restore_path = Some(std::path::absolute(path)?);
+        }
+        if let Some(target) = &cli.restore_to {
+            config.restore_target = RestoreTarget::from_name(target)
+                .ok_or_else(|| TrekError::Config(format!("unknown restore target '{target}'")))?;
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn apply_file(&mut self, path: &Path) -> Result<()> {
+        let text = std::fs::read_to_string(path)?;
+        let file: ConfigFile = toml::from_str(&text)
+            .map_err(|e| TrekError::Config(format!("{}: {}", path.display(), e.message())))?;
+        file.apply(self)
+            .map_err(|message| TrekError::Config(format!("{}: {message}", path.display())))
+    }
+
+    fn validate(&self) -> Result<()> {
+        let h = &self.health;
+        if !(0.0 < h.trauma && h.trauma < h.shrank && h.shrank <= 1.0 && 1.0 <= h.grew) {
+            return Err(TrekError::Config(
+                "health thresholds must satisfy 0 < trauma < shrank <= 1 <= grew".to_string(),
+            ));
+        }
+        let i = &self.intensity;
+        if !(0 < i.tremor && i.tremor < i.quake && i.quake < i.major) {
+            return Err(TrekError::Config(
+                "intensity thresholds must satisfy 0 < tremor < quake < major".to_string(),
+            ));
+        }
+        if !(self.playback.speed.is_finite() && self.playback.speed > 0.0) {
+            return Err(TrekError::Config(
+                "playback speed must be greater than 0".to_string(),
+            ));
+        }
+        if self.limit == 0 {
+            return Err(TrekError::Config("limit must be at least 1".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// `$XDG_CONFIG_HOME/git-trek/config.toml`, falling back to `~/.config`.
+fn user_config_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("git-trek").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests;
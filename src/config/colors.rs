@@ -0,0 +1,122 @@
+use ratatui::style::Color;
+
+/// Colors used by the Terrain treemap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TerrainColors {
+    pub stable: Color,
+    pub grew: Color,
+    pub shrank: Color,
+    pub trauma: Color,
+    pub new: Color,
+    pub hover: Color,
+    pub selected: Color,
+}
+
+impl Default for TerrainColors {
+    fn default() -> Self {
+        Self {
+            stable: Color::Rgb(46, 49, 55),
+            grew: Color::Rgb(80, 180, 120),
+            shrank: Color::Rgb(180, 160, 80),
+            trauma: Color::Rgb(200, 60, 60),
+            new: Color::Rgb(80, 140, 200),
+            hover: Color::Rgb(255, 100, 255),
+            selected: Color::Rgb(0, 220, 220),
+        }
+    }
+}
+
+/// Colors used by the Seismic grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SeismicColors {
+    pub quiet: Color,
+    pub tremor: Color,
+    pub quake: Color,
+    pub major: Color,
+    pub disaster: Color,
+    pub created: Color,
+    pub deleted: Color,
+}
+
+impl Default for SeismicColors {
+    fn default() -> Self {
+        Self {
+            quiet: Color::Rgb(30, 32, 35),
+            tremor: Color::Rgb(60, 80, 60),
+            quake: Color::Rgb(120, 140, 60),
+            major: Color::Rgb(200, 160, 40),
+            disaster: Color::Rgb(220, 60, 40),
+            created: Color::Rgb(40, 80, 100),
+            deleted: Color::Rgb(80, 40, 40),
+        }
+    }
+}
+
+/// A named palette for both views; `[colors.*]` entries are applied on top of it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Theme {
+    #[default]
+    Default,
+    /// Blue/orange/vermillion palette that stays distinct for red-green colorblindness.
+    Colorblind,
+    HighContrast,
+}
+
+impl Theme {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "default" => Some(Self::Default),
+            "colorblind" => Some(Self::Colorblind),
+            "high-contrast" | "high_contrast" => Some(Self::HighContrast),
+            _ => None,
+        }
+    }
+
+    pub fn terrain(self) -> TerrainColors {
+        match self {
+            Self::Default => TerrainColors::default(),
+            Self::Colorblind => TerrainColors {
+                stable: Color::Rgb(46, 49, 55),
+                grew: Color::Rgb(0, 114, 178),
+                shrank: Color::Rgb(240, 228, 66),
+                trauma: Color::Rgb(213, 94, 0),
+                new: Color::Rgb(204, 121, 167),
+                hover: Color::Rgb(86, 180, 233),
+                selected: Color::Rgb(240, 240, 240),
+            },
+            Self::HighContrast => TerrainColors {
+                stable: Color::Rgb(128, 128, 128),
+                grew: Color::Rgb(0, 255, 0),
+                shrank: Color::Rgb(255, 255, 0),
+                trauma: Color::Rgb(255, 0, 0),
+                new: Color::Rgb(0, 255, 255),
+                hover: Color::Rgb(255, 0, 255),
+                selected: Color::Rgb(255, 255, 255),
+            },
+        }
+    }
+
+    pub fn seismic(self) -> SeismicColors {
+        match self {
+            Self::Default => SeismicColors::default(),
+            Self::Colorblind => SeismicColors {
+                quiet: Color::Rgb(30, 32, 35),
+                tremor: Color::Rgb(40, 70, 110),
+                quake: Color::Rgb(0, 114, 178),
+                major: Color::Rgb(230, 159, 0),
+                disaster: Color::Rgb(213, 94, 0),
+                created: Color::Rgb(86, 180, 233),
+                deleted: Color::Rgb(204, 121, 167),
+            },
+            Self::HighContrast => SeismicColors {
+                quiet: Color::Rgb(90, 90, 90),
+                tremor: Color::Rgb(0, 200, 255),
+                quake: Color::Rgb(255, 255, 0),
+                major: Color::Rgb(255, 140, 0),
+                disaster: Color::Rgb(255, 0, 0),
+                created: Color::Rgb(0, 255, 0),
+                deleted: Color::Rgb(255, 0, 255),
+            },
+        }
+    }
+}
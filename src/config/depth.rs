@@ -0,0 +1,154 @@
+use ratatui::style::Color;
+
+/// How many colors the terminal can show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ColorDepth {
+    #[default]
+    TrueColor,
+    Ansi256,
+    Ansi16,
+    /// No color at all, as requested by `NO_COLOR`.
+    None,
+}
+
+/// RGB values of the 16 ANSI colors, as xterm renders them.
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Channel values of the 6x6x6 cube in the 256-color palette.
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl ColorDepth {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "truecolor" | "24bit" => Some(Self::TrueColor),
+            "256" => Some(Self::Ansi256),
+            "16" => Some(Self::Ansi16),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    /// Guess the depth from `NO_COLOR`, `COLORTERM` and `TERM`.
+    pub fn detect() -> Self {
+        Self::detect_with(|name| std::env::var(name).unwrap_or_default())
+    }
+
+    /// Guess the depth from environment variables read through `var`, with unset
+    /// variables read as empty.
+    fn detect_with(var: impl Fn(&str) -> String) -> Self {
+        if !var("NO_COLOR").is_empty() {
+            return Self::None;
+        }
+        let colorterm = var("COLORTERM");
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return Self::TrueColor;
+        }
+        let term = var("TERM");
+        if term == "dumb" {
+            Self::None
+        } else if term.contains("256color") {
+            Self::Ansi256
+        } else {
+            Self::Ansi16
+        }
+    }
+
+    /// The closest color this depth can show; `None` maps everything to the terminal default.
+    pub fn adapt(self, color: Color) -> Color {
+        if color == Color::Reset {
+            return color;
+        }
+        match self {
+            Self::TrueColor => color,
+            Self::Ansi256 => match color {
+                Color::Rgb(r, g, b) => Color::Indexed(to_256(r, g, b)),
+                other => other,
+            },
+            Self::Ansi16 => match to_rgb(color) {
+                Some((r, g, b)) => nearest_ansi16(r, g, b),
+                None => color,
+            },
+            Self::None => Color::Reset,
+        }
+    }
+}
+
+fn to_256(r: u8, g: u8, b: u8) -> u8 {
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = (cube_value(ri), cube_value(gi), cube_value(bi));
+
+    let avg = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let step = u8::try_from(avg.saturating_sub(3) / 10)
+        .unwrap_or(23)
+        .min(23);
+    let level = 8 + step * 10;
+
+    if distance((r, g, b), (level, level, level)) < distance((r, g, b), cube) {
+        232 + step
+    } else {
+        16 + 36 * ri + 6 * gi + bi
+    }
+}
+
+fn cube_index(v: u8) -> u8 {
+    let mut best = 0;
+    for (i, &level) in (0u8..).zip(CUBE.iter()) {
+        if v.abs_diff(level) < v.abs_diff(cube_value(best)) {
+            best = i;
+        }
+    }
+    best
+}
+
+fn cube_value(i: u8) -> u8 {
+    CUBE.get(usize::from(i)).copied().unwrap_or(255)
+}
+
+/// RGB value of any color except `Reset`.
+fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        Color::Indexed(i) if i < 16 => ANSI16.get(usize::from(i)).map(|(_, rgb)| *rgb),
+        Color::Indexed(i) if i >= 232 => {
+            let level = 8 + (i - 232) * 10;
+            Some((level, level, level))
+        }
+        Color::Indexed(i) => {
+            let i = i - 16;
+            Some((cube_value(i / 36), cube_value(i / 6 % 6), cube_value(i % 6)))
+        }
+        _ => None,
+    }
+}
+
+fn nearest_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| u32::from(x.abs_diff(y)).pow(2);
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+#[cfg(test)]
+mod tests;
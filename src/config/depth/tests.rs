@@ -0,0 +1,77 @@
+use super::*;
+
+fn detect(vars: &[(&str, &str)]) -> ColorDepth {
+    ColorDepth::detect_with(|name| {
+        vars.iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| (*value).to_string())
+            .unwrap_or_default()
+    })
+}
+
+#[test]
+fn no_color_wins_over_everything() {
+    let vars = [
+        ("NO_COLOR", "1"),
+        ("COLORTERM", "truecolor"),
+        ("TERM", "xterm-256color"),
+    ];
+    assert_eq!(detect(&vars), ColorDepth::None);
+    // An empty NO_COLOR does not count as set.
+    assert_eq!(
+        detect(&[("NO_COLOR", ""), ("COLORTERM", "24bit")]),
+        ColorDepth::TrueColor
+    );
+}
+
+#[test]
+fn colorterm_wins_over_term() {
+    let vars = [("COLORTERM", "truecolor"), ("TERM", "dumb")];
+    assert_eq!(detect(&vars), ColorDepth::TrueColor);
+    let vars = [("COLORTERM", "yes"), ("TERM", "xterm-256color")];
+    assert_eq!(detect(&vars), ColorDepth::Ansi256);
+}
+
+#[test]
+fn term_picks_the_depth_last() {
+    assert_eq!(detect(&[("TERM", "screen-256color")]), ColorDepth::Ansi256);
+    assert_eq!(detect(&[("TERM", "dumb")]), ColorDepth::None);
+    assert_eq!(detect(&[("TERM", "xterm")]), ColorDepth::Ansi16);
+    assert_eq!(detect(&[]), ColorDepth::Ansi16);
+}
+
+#[test]
+fn adapt_to_256_uses_the_cube_or_the_gray_ramp() {
+    let depth = ColorDepth::Ansi256;
+    assert_eq!(depth.adapt(Color::Rgb(255, 0, 0)), Color::Indexed(196));
+    assert_eq!(depth.adapt(Color::Rgb(0, 0, 0)), Color::Indexed(16));
+    assert_eq!(depth.adapt(Color::Rgb(128, 128, 128)), Color::Indexed(244));
+    assert_eq!(depth.adapt(Color::Red), Color::Red);
+}
+
+#[test]
+fn adapt_to_16_picks_the_nearest_ansi_color() {
+    let depth = ColorDepth::Ansi16;
+    assert_eq!(depth.adapt(Color::Rgb(250, 10, 10)), Color::LightRed);
+    assert_eq!(depth.adapt(Color::Rgb(0, 0, 230)), Color::Blue);
+    assert_eq!(depth.adapt(Color::Indexed(196)), Color::LightRed);
+    assert_eq!(depth.adapt(Color::Indexed(244)), Color::DarkGray);
+    assert_eq!(depth.adapt(Color::Green), Color::Green);
+}
+
+#[test]
+fn adapt_keeps_reset_and_drops_color_without_depth() {
+    for depth in [
+        ColorDepth::TrueColor,
+        ColorDepth::Ansi256,
+        ColorDepth::Ansi16,
+    ] {
+        assert_eq!(depth.adapt(Color::Reset), Color::Reset);
+    }
+    assert_eq!(
+        ColorDepth::TrueColor.adapt(Color::Rgb(1, 2, 3)),
+        Color::Rgb(1, 2, 3)
+    );
+    assert_eq!(ColorDepth::None.adapt(Color::Rgb(1, 2, 3)), Color::Reset);
+    assert_eq!(ColorDepth::None.adapt(Color::Yellow), Color::Reset);
+}
@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use ratatui::style::Color;
+use serde::Deserialize;
+
+use crate::keymap::{Action, KeyChord};
+use crate::views::ViewMode;
+
+use super::{ColorDepth, Config, Pace, StopAt, Theme};
+
+/// On-disk layout of a config file; every field is optional so files can layer.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    limit: Option<usize>,
+    default_view: Option<String>,
+    /// `default`, `colorblind` or `high-contrast`.
+    theme: Option<String>,
+    /// `truecolor`, `256`, `16` or `none`; detected from the terminal when unset.
+    color_depth: Option<String>,
+    #[serde(default)]
+    health: HealthFile,
+    #[serde(default)]
+    intensity: IntensityFile,
+    #[serde(default)]
+    playback: PlaybackFile,
+    #[serde(default)]
+    colors: ColorsFile,
+    /// Action name to one key sequence or a list of them, e.g. `next_commit = ["right", "l"]`.
+    #[serde(default)]
+    keys: BTreeMap<String, KeySpec>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum KeySpec {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct HealthFile {
+    trauma: Option<f64>,
+    shrank: Option<f64>,
+    grew: Option<f64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IntensityFile {
+    tremor: Option<usize>,
+    quake: Option<usize>,
+    major: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PlaybackFile {
+    speed: Option<f64>,
+    /// `steady` or `time`.
+    pace: Option<String>,
+    /// `none`, `tremor`, `quake`, `major` or `disaster`.
+    stop_at: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ColorsFile {
+    #[serde(default)]
+    terrain: BTreeMap<String, String>,
+    #[serde(default)]
+    seismic: BTreeMap<String, String>,
+}
+
+impl ConfigFile {
+    /// Overlay the values set in this file onto `config`.
+    pub fn apply(self, config: &mut Config) -> std::result::Result<(), String> {
+        set(&mut config.limit, self.limit);
+        if let Some(name) = self.default_view {
+            config.default_view =
+                ViewMode::from_name(&name).ok_or(format!("unknown default_view '{name}'"))?;
+        }
+
+        if let Some(name) = self.theme {
+            let theme = Theme::from_name(&name).ok_or(format!("unknown theme '{name}'"))?;
+            config.terrain_colors = theme.terrain();
+            config.seismic_colors = theme.seismic();
+        }
+        if let Some(name) = self.color_depth {
+            config.color_depth =
+                ColorDepth::from_name(&name).ok_or(format!("unknown color_depth '{name}'"))?;
+        }
+
+        set(&mut config.health.trauma, self.health.trauma);
+        set(&mut config.health.shrank, self.health.shrank);
+        set(&mut config.health.grew, self.health.grew);
+        set(&mut config.intensity.tremor, self.intensity.tremor);
+        set(&mut config.intensity.quake, self.intensity.quake);
+        set(&mut config.intensity.major, self.intensity.major);
+
+        set(&mut config.playback.speed, self.playback.speed);
+        if let Some(name) = self.playback.pace {
+            config.playback.pace =
+                Pace::from_name(&name).ok_or(format!("unknown playback pace '{name}'"))?;
+        }
+        if let Some(name) = self.playback.stop_at {
+            config.playback.stop_at =
+                StopAt::from_name(&name).ok_or(format!("unknown playback stop_at '{name}'"))?;
+        }
+
+        for (key, value) in &self.colors.terrain {
+            let colors = &mut config.terrain_colors;
+            let slot = match key.as_str() {
+                "stable" => &mut colors.stable,
+                "grew" => &mut colors.grew,
+                "shrank" => &mut colors.shrank,
+                "trauma" => &mut colors.trauma,
+                "new" => &mut colors.new,
+                "hover" => &mut colors.hover,
+                "selected" => &mut colors.selected,
+                _ => return Err(format!("unknown terrain color '{key}'")),
+            };
+            *slot = parse_color(value)?;
+        }
+
+        for (key, value) in &self.colors.seismic {
+            let colors = &mut config.seismic_colors;
+            let slot = match key.as_str() {
+                "quiet" => &mut colors.quiet,
+                "tremor" => &mut colors.tremor,
+                "quake" => &mut colors.quake,
+                "major" => &mut colors.major,
+                "disaster" => &mut colors.disaster,
+                "created" => &mut colors.created,
+                "deleted" => &mut colors.deleted,
+                _ => return Err(format!("unknown seismic color '{key}'")),
+            };
+            *slot = parse_color(value)?;
+        }
+
+        for (name, spec) in self.keys {
+            let action = Action::from_name(&name).ok_or(format!("unknown action '{name}'"))?;
+            let texts = match spec {
+                KeySpec::One(text) => vec![text],
+                KeySpec::Many(texts) => texts,
+            };
+            let sequences = texts
+                .iter()
+                .map(|text| KeyChord::parse_sequence(text).ok_or(format!("invalid key '{text}'")))
+                .collect::<std::result::Result<_, _>>()?;
+            config.keymap.bind(action, sequences);
+        }
+
+        Ok(())
+    }
+}
+
+fn set<T>(slot: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *slot = value;
+    }
+}
+
+/// Parse `#rrggbb`, a 0-255 palette index, or a named color such as `cyan`.
+fn parse_color(value: &str) -> std::result::Result<Color, String> {
+    Color::from_str(value).map_err(|_| format!("invalid color '{value}'"))
+}
@@ -0,0 +1,75 @@
+use super::IntensityThresholds;
+
+/// How long playback waits between commits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Pace {
+    /// A fixed number of commits per second.
+    #[default]
+    Steady,
+    /// Waits scale with the real time between commits, averaging the same speed.
+    Time,
+}
+
+impl Pace {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "steady" => Some(Self::Steady),
+            "time" => Some(Self::Time),
+            _ => None,
+        }
+    }
+}
+
+/// Smallest seismic intensity that pauses playback.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum StopAt {
+    #[default]
+    Never,
+    Tremor,
+    Quake,
+    Major,
+    Disaster,
+}
+
+impl StopAt {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "none" | "never" => Some(Self::Never),
+            "tremor" => Some(Self::Tremor),
+            "quake" => Some(Self::Quake),
+            "major" => Some(Self::Major),
+            "disaster" => Some(Self::Disaster),
+            _ => None,
+        }
+    }
+
+    /// Smallest line delta that reaches this intensity.
+    pub fn min_delta(self, thresholds: &IntensityThresholds) -> Option<usize> {
+        match self {
+            Self::Never => None,
+            Self::Tremor => Some(1),
+            Self::Quake => Some(thresholds.tremor + 1),
+            Self::Major => Some(thresholds.quake + 1),
+            Self::Disaster => Some(thresholds.major + 1),
+        }
+    }
+}
+
+/// Settings for history playback.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlaybackConfig {
+    /// Commits per second.
+    pub speed: f64,
+    pub pace: Pace,
+    pub stop_at: StopAt,
+}
+
+impl Default for PlaybackConfig {
+    fn default() -> Self {
+        Self {
+            speed: 2.0,
+            pace: Pace::default(),
+            stop_at: StopAt::default(),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use super::*;
+
+fn invalid(config: &Config) -> String {
+    match config.validate() {
+        Err(TrekError::Config(message)) => message,
+        other => format!("unexpected {other:?}"),
+    }
+}
+
+#[test]
+fn defaults_are_valid() {
+    assert!(Config::default().validate().is_ok());
+}
+
+#[test]
+fn health_thresholds_must_be_ordered() {
+    for (trauma, shrank, grew) in [
+        (0.0, 0.95, 1.05),
+        (0.95, 0.7, 1.05),
+        (0.7, 1.2, 1.3),
+        (0.7, 0.95, 0.99),
+    ] {
+        let config = Config {
+            health: HealthThresholds {
+                trauma,
+                shrank,
+                grew,
+            },
+            ..Config::default()
+        };
+        assert!(
+            invalid(&config).starts_with("health thresholds"),
+            "{trauma} {shrank} {grew}"
+        );
+    }
+    let boundary = Config {
+        health: HealthThresholds {
+            trauma: 0.5,
+            shrank: 1.0,
+            grew: 1.0,
+        },
+        ..Config::default()
+    };
+    assert!(boundary.validate().is_ok());
+}
+
+#[test]
+fn intensity_thresholds_must_increase() {
+    for (tremor, quake, major) in [(0, 50, 200), (50, 50, 200), (10, 300, 200)] {
+        let config = Config {
+            intensity: IntensityThresholds {
+                tremor,
+                quake,
+                major,
+            },
+            ..Config::default()
+        };
+        assert!(
+            invalid(&config).starts_with("intensity thresholds"),
+            "{tremor} {quake} {major}"
+        );
+    }
+}
+
+#[test]
+fn playback_speed_and_limit_must_be_positive() {
+    for speed in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        let mut config = Config::default();
+        config.playback.speed = speed;
+        assert!(invalid(&config).starts_with("playback speed"), "{speed}");
+    }
+    let config = Config {
+        limit: 0,
+        ..Config::default()
+    };
+    assert_eq!(invalid(&config), "limit must be at least 1");
+}
+
+#[test]
+fn apply_file_layers_values_and_names_the_file() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("config.toml");
+    std::fs::write(&path, "limit = 50\n[health]\ntrauma = 0.5\n")?;
+    let mut config = Config::default();
+    config.apply_file(&path)?;
+    assert_eq!(config.limit, 50);
+    assert!((config.health.trauma - 0.5).abs() < f64::EPSILON);
+    assert!(config.validate().is_ok());
+
+    std::fs::write(&path, "limt = 50\n")?;
+    let err = Config::default().apply_file(&path);
+    assert!(matches!(err, Err(TrekError::Config(m)) if m.starts_with(&path.display().to_string())));
+    Ok(())
+}
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use git2::Oid;
 
+use crate::config::HealthThresholds;
+
 /// Health status of a file based on change magnitude.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum Health {
@@ -16,26 +18,41 @@ pub enum Health {
 
 impl Health {
     /// Determine health from old and new line counts.
-    pub fn from_change(old: Option<usize>, new: Option<usize>) -> Self {
+    pub fn from_change(
+        old: Option<usize>,
+        new: Option<usize>,
+        thresholds: &HealthThresholds,
+    ) -> Self {
         match (old, new) {
             (None, None) => Self::Stable,
             (None, Some(_)) => Self::New,
             (Some(_), None) => Self::Deleted,
-            (Some(o), Some(n)) => Self::from_ratio(o, n),
+            (Some(o), Some(n)) => Self::from_ratio(o, n, thresholds),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Grew => "grew",
+            Self::Shrank => "shrank",
+            Self::Trauma => "trauma",
+            Self::New => "new",
+            Self::Deleted => "deleted",
         }
     }
 
     #[allow(clippy::cast_precision_loss)]
-    fn from_ratio(old: usize, new: usize) -> Self {
+    fn from_ratio(old: usize, new: usize, thresholds: &HealthThresholds) -> Self {
         if old == 0 {
             return Self::New;
         }
         let ratio = new as f64 / old as f64;
-        if ratio < 0.7 {
+        if ratio < thresholds.trauma {
             Self::Trauma
-        } else if ratio < 0.95 {
+        } else if ratio < thresholds.shrank {
             Self::Shrank
-        } else if ratio > 1.05 {
+        } else if ratio > thresholds.grew {
             Self::Grew
         } else {
             Self::Stable
@@ -60,11 +77,85 @@ impl FileHistory {
         self.snapshots.get(&commit_idx).map(|s| s.lines)
     }
 
-    pub fn health_at(&self, commit_idx: usize, prev_idx: Option<usize>) -> Health {
+    pub fn health_at(
+        &self,
+        commit_idx: usize,
+        prev_idx: Option<usize>,
+        thresholds: &HealthThresholds,
+    ) -> Health {
         let new_lines = self.lines_at(commit_idx);
         let old_lines = prev_idx.and_then(|i| self.lines_at(i));
-        Health::from_change(old_lines, new_lines)
+        Health::from_change(old_lines, new_lines, thresholds)
+    }
+
+    /// Oldest loaded commit at which the file exists.
+    pub fn first_seen(&self) -> Option<usize> {
+        self.snapshots.keys().max().copied()
+    }
+
+    /// Newest commit whose line count differs from its parent's.
+    pub fn last_touched(&self) -> Option<usize> {
+        let first_seen = self.first_seen()?;
+        (0..=first_seen).find(|&i| self.lines_at(i) != self.lines_at(i + 1))
+    }
+
+    /// How much the file churned over `start..end`: lines changed against the
+    /// neighbouring commit, with 10 for each appearance or disappearance.
+    pub fn activity_score(&self, start: usize, end: usize) -> u64 {
+        let mut score = 0u64;
+        for i in start..end {
+            let prev_lines = if i == 0 { None } else { self.lines_at(i - 1) };
+            let curr_lines = self.lines_at(i);
+
+            if let (Some(prev), Some(curr)) = (prev_lines, curr_lines) {
+                score += prev.abs_diff(curr) as u64;
+            } else if prev_lines.is_some() || curr_lines.is_some() {
+                score += 10;
+            }
+        }
+        score
+    }
+
+    /// Commit that deleted the file, when it is missing at the newest loaded commit.
+    pub fn deleted_at(&self) -> Option<usize> {
+        self.snapshots.keys().min().and_then(|&i| i.checked_sub(1))
     }
+
+    /// Signed line delta between two commits, if the file exists at both.
+    pub fn delta_at(&self, commit_idx: usize, prev_idx: Option<usize>) -> Option<i64> {
+        let new_lines = i64::try_from(self.lines_at(commit_idx)?).ok()?;
+        let old_lines = i64::try_from(self.lines_at(prev_idx?)?).ok()?;
+        Some(new_lines - old_lines)
+    }
+
+    /// Signed line delta between two commits, counting the file as empty where it is
+    /// missing, so creating and deleting it count in full.
+    pub fn size_change_at(&self, commit_idx: usize, prev_idx: usize) -> Option<i64> {
+        let (new_lines, old_lines) = (self.lines_at(commit_idx), self.lines_at(prev_idx));
+        if new_lines.is_none() && old_lines.is_none() {
+            return None;
+        }
+        let new_lines = i64::try_from(new_lines.unwrap_or(0)).ok()?;
+        let old_lines = i64::try_from(old_lines.unwrap_or(0)).ok()?;
+        Some(new_lines - old_lines)
+    }
+}
+
+/// What a ref decorating a commit is, in display order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RefKind {
+    Head,
+    Branch,
+    Remote,
+    Tag,
+}
+
+/// HEAD, a branch or a tag pointing at a commit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefLabel {
+    pub kind: RefKind,
+    /// Short name, such as `main`, `origin/main` or `v1.0`.
+    pub name: String,
 }
 
 /// Information about a single commit.
@@ -73,17 +164,68 @@ pub struct Commit {
     pub oid: Oid,
     pub short_oid: String,
     pub summary: String,
+    /// Commit time in seconds since the Unix epoch.
+    pub time: i64,
+    pub author: String,
+    /// Message text after the summary line.
+    pub body: String,
+    /// HEAD, branches and tags pointing at this commit, HEAD first.
+    pub refs: Vec<RefLabel>,
 }
 
 impl Commit {
-    pub fn new(oid: Oid, summary: String) -> Self {
+    pub fn new(oid: Oid, summary: String, time: i64) -> Self {
         let short_oid = oid.to_string()[..8].to_string();
         Self {
             oid,
             short_oid,
             summary,
+            time,
+            author: String::new(),
+            body: String::new(),
+            refs: Vec::new(),
         }
     }
+
+    /// Whether a tag points at this commit.
+    pub fn is_tagged(&self) -> bool {
+        self.refs.iter().any(|r| r.kind == RefKind::Tag)
+    }
+
+    /// Ref names joined for display, such as `HEAD, main, v1.0`.
+    pub fn ref_names(&self) -> String {
+        self.refs
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Whether the commit matches a search query by message, author, ref name or sha prefix.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        if query.is_empty() {
+            return false;
+        }
+        self.oid.to_string().starts_with(&query)
+            || self.summary.to_lowercase().contains(&query)
+            || self.body.to_lowercase().contains(&query)
+            || self.author.to_lowercase().contains(&query)
+            || self
+                .refs
+                .iter()
+                .any(|r| r.name.to_lowercase().contains(&query))
+    }
+}
+
+/// A file missing at the newest loaded commit, and where it went.
+#[derive(Clone, Debug)]
+pub struct Grave {
+    pub path: String,
+    /// Commit that deleted the file.
+    pub deleted_at: usize,
+    /// Its parent, holding the last version before the deletion.
+    pub last_seen: usize,
 }
 
 /// Complete repository data loaded for visualization.
@@ -108,7 +250,33 @@ impl History {
                     .map(|lines| (path.as_str(), lines))
             })
             .collect();
-        files.sort_by(|a, b| b.1.cmp(&a.1));
+        files.sort_by_key(|f| std::cmp::Reverse(f.1));
         files
     }
+
+    /// Every file deleted within the loaded commits, most recently deleted first.
+    pub fn graveyard(&self) -> Vec<Grave> {
+        let mut graves: Vec<Grave> = self
+            .files
+            .iter()
+            .filter_map(|(path, history)| {
+                history.deleted_at().map(|deleted_at| Grave {
+                    path: path.clone(),
+                    deleted_at,
+                    last_seen: deleted_at + 1,
+                })
+            })
+            .collect();
+        graves.sort_by(|a, b| a.deleted_at.cmp(&b.deleted_at).then(a.path.cmp(&b.path)));
+        graves
+    }
 }
+
+/// Parent directory of a path with a trailing slash, or `./` for top-level files.
+pub fn parent_dir(path: &str) -> String {
+    path.rsplit_once('/')
+        .map_or_else(|| "./".to_string(), |(dir, _)| format!("{dir}/"))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,92 @@
+use super::*;
+use crate::error::Result;
+use crate::git_ops::{self, testing};
+
+fn commit() -> Result<Commit> {
+    let oid = Oid::from_str("a1b2c3d4e5f60718293a4b5c6d7e8f9012345678")?;
+    Ok(Commit {
+        author: "Grace Hopper".to_string(),
+        body: "Refs JIRA-1234.\n".to_string(),
+        refs: vec![RefLabel {
+            kind: RefKind::Tag,
+            name: "v1.2".to_string(),
+        }],
+        ..Commit::new(oid, "Fix the parser".to_string(), 0)
+    })
+}
+
+#[test]
+fn matches_summary_body_author_and_refs_ignoring_case() -> Result<()> {
+    let commit = commit()?;
+    assert!(commit.matches("parser"));
+    assert!(commit.matches("FIX THE"));
+    assert!(commit.matches("jira-1234"));
+    assert!(commit.matches("hopper"));
+    assert!(commit.matches("V1.2"));
+    Ok(())
+}
+
+#[test]
+fn matches_sha_prefixes_only() -> Result<()> {
+    let commit = commit()?;
+    assert!(commit.matches("a1b2c3"));
+    assert!(commit.matches("A1B2C3"));
+    assert!(!commit.matches("c3d4e5"));
+    Ok(())
+}
+
+#[test]
+fn matches_nothing_for_other_or_empty_queries() -> Result<()> {
+    let commit = commit()?;
+    assert!(!commit.matches("lexer"));
+    assert!(!commit.matches("JIRA-999"));
+    assert!(!commit.matches(""));
+    Ok(())
+}
+
+fn grave_summary(history: &History) -> Vec<(String, usize, usize)> {
+    history
+        .graveyard()
+        .into_iter()
+        .map(|g| (g.path, g.deleted_at, g.last_seen))
+        .collect()
+}
+
+#[test]
+fn graveyard_lists_the_latest_deletion_of_a_re_added_file() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    testing::commit(
+        &repo,
+        &[("gone.rs", Some("v1\n")), ("back.rs", Some("b\n"))],
+    )?;
+    testing::commit(&repo, &[("gone.rs", None), ("back.rs", None)])?;
+    testing::commit(
+        &repo,
+        &[("gone.rs", Some("v2\n")), ("back.rs", Some("b\n"))],
+    )?;
+    testing::commit(&repo, &[("gone.rs", None)])?;
+
+    // Newest first: `gone.rs` was deleted again at 0 after being re-added at 1,
+    // and `back.rs` is still there.
+    let history = git_ops::load_history(&repo, 10)?;
+    assert_eq!(grave_summary(&history), [("gone.rs".to_string(), 0, 1)]);
+    let last = history.commits.get(1).map_or_else(Oid::zero, |c| c.oid);
+    assert_eq!(git_ops::file_bytes(&repo, last, "gone.rs")?, b"v2\n");
+    Ok(())
+}
+
+#[test]
+fn graveyard_needs_the_last_version_inside_the_loaded_window() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    testing::commit(&repo, &[("old.rs", Some("old\n"))])?;
+    testing::commit(&repo, &[("old.rs", None), ("new.rs", Some("new\n"))])?;
+    testing::commit(&repo, &[("new.rs", Some("newer\n"))])?;
+
+    // The file's last version is the oldest loaded commit.
+    let history = git_ops::load_history(&repo, 3)?;
+    assert_eq!(grave_summary(&history), [("old.rs".to_string(), 1, 2)]);
+    // The oldest loaded commit deleted it, so no loaded version is left to restore.
+    let history = git_ops::load_history(&repo, 2)?;
+    assert!(history.graveyard().is_empty());
+    Ok(())
+}
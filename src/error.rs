@@ -14,8 +14,20 @@ pub enum TrekError {
     #[error("No commits found in repository")]
     NoCommits,
 
-    #[error("Invalid UTF-8 in file content")]
-    InvalidUtf8,
+    #[error("Cannot restore files in a bare repository")]
+    BareRepository,
+
+    #[error("Config error: {0}")]
+    Config(String),
+
+    #[error("Bookmark error: {0}")]
+    Marks(String),
+
+    #[error("Report error: {0}")]
+    Report(String),
+
+    #[error("Invalid pattern: {0}")]
+    Pattern(#[from] regex::Error),
 }
 
 pub type Result<T> = std::result::Result<T, TrekError>;
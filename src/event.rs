@@ -1,32 +1,51 @@
-use std::io;
+mod finder;
+mod graveyard;
+mod help;
+mod marks;
+mod palette;
+mod pickaxe;
+mod refs;
+mod report;
+mod restore;
+mod search;
+mod viewer;
 
 use anyhow::Result;
-use crossterm::{
-    event::{
-        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, MouseEvent,
-        MouseEventKind,
-    },
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
-use ratatui::{backend::CrosstermBackend, Terminal};
+use crossterm::event::{self, Event, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 
 use crate::app::App;
+use crate::keymap::{Action, KeyChord};
 use crate::mouse::{hit_test, HitBox};
+use crate::terminal::{self, Tui};
 
-pub fn run(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App) -> Result<()> {
+pub fn run(terminal: &mut Tui, app: &mut App) -> Result<()> {
     let mut hit_boxes: Vec<HitBox> = Vec::new();
 
     while !app.should_quit() {
+        app.refresh_viewer()?;
         terminal.draw(|f| {
             let render = crate::views::draw(f, app);
+            app.apply_seismic_reveal(&render.seismic_rows);
+            app.record_layout(&render.hit_boxes, &render.seismic_rows);
+            if let Some(height) = render.viewer_height {
+                app.record_viewer_height(height);
+            }
             hit_boxes = render.hit_boxes;
         })?;
 
+        if let Some(open) = app.take_external() {
+            let status = terminal::suspend(terminal, || open.command()?.status())?;
+            app.finish_external(&open, status);
+            continue;
+        }
+
+        app.poll_pickaxe();
+        app.tick_playback();
+
         if event::poll(std::time::Duration::from_millis(50))? {
             match event::read()? {
                 Event::Key(key) => handle_key(app, key)?,
-                Event::Mouse(mouse) => handle_mouse(app, mouse, &hit_boxes),
+                Event::Mouse(mouse) => handle_mouse(app, mouse, &hit_boxes)?,
                 _ => {}
             }
         }
@@ -36,78 +55,71 @@ pub fn run(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
 }
 
 fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
-    if let Some(mode) = view_from_key(key.code) {
-        app.set_view(mode);
+    app.clear_notice();
+
+    if app.restore_prompt().is_some() {
+        restore::handle_key(app, key);
         return Ok(());
     }
 
-    if handle_navigation(app, key.code) {
+    if app.help_scroll().is_some() {
+        help::handle_key(app, key);
         return Ok(());
     }
 
-    match key.code {
-        KeyCode::Char('q' | 'Q') => {
-            app.quit();
-        }
-        KeyCode::Char('r' | 'R') => {
-            app.restore_selected()?;
-        }
-        KeyCode::Char('f' | 'F') => {
-            app.toggle_seismic_filter();
-        }
-        KeyCode::Esc => {
-            app.clear_selection();
-        }
-        _ => {}
+    if app.report().is_some() {
+        report::handle_key(app, key);
+        return Ok(());
     }
 
-    Ok(())
-}
+    if app.mark_prompt().is_some() {
+        marks::handle_key(app, key);
+        return Ok(());
+    }
 
-fn view_from_key(code: KeyCode) -> Option<crate::views::ViewMode> {
-    use crate::views::ViewMode;
-    match code {
-        KeyCode::Char('1') => Some(ViewMode::Terrain),
-        KeyCode::Char('2') => Some(ViewMode::Seismic),
-        KeyCode::Char('3') => Some(ViewMode::Strata),
-        KeyCode::Char('4') => Some(ViewMode::Flow),
-        KeyCode::Char('5') => Some(ViewMode::Constellation),
-        KeyCode::Char('6') => Some(ViewMode::Surgery),
-        _ => None,
+    if app.ref_prompt().is_some() {
+        refs::handle_key(app, key);
+        return Ok(());
     }
-}
 
-fn handle_navigation(app: &mut App, code: KeyCode) -> bool {
-    match code {
-        KeyCode::Left => {
-            app.scroll_timeline(1);
-            true
-        }
-        KeyCode::Right => {
-            app.scroll_timeline(-1);
-            true
-        }
-        KeyCode::Up | KeyCode::Char('k') => {
-            app.seismic_scroll_vertical(-1);
-            true
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            app.seismic_scroll_vertical(1);
-            true
-        }
-        KeyCode::Tab => {
-            app.next_view();
-            true
-        }
-        KeyCode::BackTab => {
-            app.prev_view();
-            true
-        }
-        _ => false,
+    if app.palette().is_some() {
+        palette::handle_key(app, key)?;
+        return Ok(());
     }
+
+    if app.graveyard().is_some() {
+        graveyard::handle_key(app, key);
+        return Ok(());
+    }
+
+    if app.finder().is_some() {
+        finder::handle_key(app, key);
+        return Ok(());
+    }
+
+    if app.viewer().is_some() {
+        viewer::handle_key(app, key)?;
+        return Ok(());
+    }
+
+    if app.commit_search().is_some_and(|s| s.editing) {
+        search::handle_key(app, key);
+        return Ok(());
+    }
+
+    if app.pickaxe().is_some_and(|p| p.editing) {
+        pickaxe::handle_key(app, key);
+        return Ok(());
+    }
+
+    if let Some(action) = app.resolve_key(KeyChord::from_event(key)) {
+        app.perform(action)?;
+    }
+
+    Ok(())
 }
 
-fn handle_mouse(app: &mut App, mouse: MouseEvent, hit_boxes: &[HitBox]) {
+fn handle_mouse(app: &mut App, mouse: MouseEvent, hit_boxes: &[HitBox]) -> Result<()> {
     app.mouse_mut().update_position(mouse.column, mouse.row);
 
     match mouse.kind {
@@ -115,16 +127,24 @@ fn handle_mouse(app: &mut App, mouse: MouseEvent, hit_boxes: &[HitBox]) {
             let target = hit_test(mouse.column, mouse.row, hit_boxes);
             app.mouse_mut().update_hover(target);
         }
-        MouseEventKind::Down(_) => {
+        MouseEventKind::Down(_) if mouse.modifiers.contains(KeyModifiers::SHIFT) => {
             let target = hit_test(mouse.column, mouse.row, hit_boxes);
-            app.handle_click(target);
-        }
-        MouseEventKind::ScrollUp => {
-            app.scroll_timeline(-1);
+            app.handle_shift_click(target)?;
         }
-        MouseEventKind::ScrollDown => {
-            app.scroll_timeline(1);
+        MouseEventKind::Down(_) => {
+            let target = hit_test(mouse.column, mouse.row, hit_boxes);
+            app.handle_click(target)?;
         }
+        MouseEventKind::ScrollUp if app.help_scroll().is_some() => app.scroll_help(-1),
+        MouseEventKind::ScrollDown if app.help_scroll().is_some() => app.scroll_help(1),
+        MouseEventKind::ScrollUp if app.report().is_some() => app.scroll_report(-3),
+        MouseEventKind::ScrollDown if app.report().is_some() => app.scroll_report(3),
+        MouseEventKind::ScrollUp if app.viewer().is_some() => app.move_viewer_cursor(-3),
+        MouseEventKind::ScrollDown if app.viewer().is_some() => app.move_viewer_cursor(3),
+        MouseEventKind::ScrollUp => app.perform(Action::PrevCommit)?,
+        MouseEventKind::ScrollDown => app.perform(Action::NextCommit)?,
         _ => {}
     }
+
+    Ok(())
 }
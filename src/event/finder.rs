@@ -0,0 +1,19 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+
+/// Route a key press to the open finder overlay.
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => app.close_finder(),
+        KeyCode::Enter => app.finder_accept(None),
+        KeyCode::Up => app.finder_move(-1),
+        KeyCode::Down => app.finder_move(1),
+        KeyCode::Char('p') if ctrl => app.finder_move(-1),
+        KeyCode::Char('n') if ctrl => app.finder_move(1),
+        KeyCode::Backspace => app.finder_backspace(),
+        KeyCode::Char(c) if !ctrl => app.finder_input(c),
+        _ => {}
+    }
+}
@@ -0,0 +1,16 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+
+/// Route a key press to the open graveyard overlay.
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q' | 'd') => app.close_graveyard(),
+        KeyCode::Enter | KeyCode::Char('r') => app.graveyard_accept(None),
+        KeyCode::Up | KeyCode::Char('k') => app.graveyard_move(-1),
+        KeyCode::Down | KeyCode::Char('j') => app.graveyard_move(1),
+        KeyCode::PageUp => app.graveyard_move(-10),
+        KeyCode::PageDown => app.graveyard_move(10),
+        _ => {}
+    }
+}
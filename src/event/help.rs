@@ -0,0 +1,15 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+
+/// Route a key press to the open help overlay.
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('?' | 'q') => app.close_help(),
+        KeyCode::Up | KeyCode::Char('k') => app.scroll_help(-1),
+        KeyCode::Down | KeyCode::Char('j') => app.scroll_help(1),
+        KeyCode::PageUp => app.scroll_help(-10),
+        KeyCode::PageDown => app.scroll_help(10),
+        _ => {}
+    }
+}
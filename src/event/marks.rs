@@ -0,0 +1,11 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+
+/// Route the letter that completes a set-mark or jump-to-mark key.
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char(c) => app.mark_prompt_letter(c),
+        _ => app.cancel_mark_prompt(),
+    }
+}
@@ -0,0 +1,21 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+
+/// Route a key press to the open command palette.
+pub fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => app.close_palette(),
+        KeyCode::Enter => app.palette_accept(None)?,
+        KeyCode::Up => app.palette_move(-1),
+        KeyCode::Down => app.palette_move(1),
+        KeyCode::Char('p') if ctrl => app.palette_move(-1),
+        KeyCode::Char('n') if ctrl => app.palette_move(1),
+        KeyCode::Backspace => app.palette_backspace(),
+        KeyCode::Char(c) if !ctrl => app.palette_input(c),
+        _ => {}
+    }
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+
+/// Route a key press to the pickaxe prompt while it is being typed.
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.clear_pickaxe(),
+        KeyCode::Enter => app.pickaxe_confirm(),
+        KeyCode::Backspace => app.pickaxe_backspace(),
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.pickaxe_input(c);
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,16 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+
+/// Route a key press to the prompt for a new branch, tag or worktree.
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.cancel_ref_prompt(),
+        KeyCode::Enter => app.confirm_ref_prompt(),
+        KeyCode::Backspace => app.ref_prompt_backspace(),
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.ref_prompt_input(c);
+        }
+        _ => {}
+    }
+}
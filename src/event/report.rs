@@ -0,0 +1,16 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+
+/// Route a key press to the open release report.
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.close_report(),
+        KeyCode::Char('w') => app.export_report(),
+        KeyCode::Up | KeyCode::Char('k') => app.scroll_report(-1),
+        KeyCode::Down | KeyCode::Char('j') => app.scroll_report(1),
+        KeyCode::PageUp => app.scroll_report(-10),
+        KeyCode::PageDown => app.scroll_report(10),
+        _ => {}
+    }
+}
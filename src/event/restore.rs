@@ -0,0 +1,20 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+use crate::git_ops::RestoreTarget;
+
+/// Route a key press to the restore confirmation dialog; the chosen-path target takes typing.
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    let typing = app
+        .restore_prompt()
+        .is_some_and(|p| p.target == RestoreTarget::Path);
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Backspace if typing => app.restore_dest_backspace(),
+        KeyCode::Char(c) if typing && !ctrl => app.restore_dest_input(c),
+        KeyCode::Tab | KeyCode::BackTab | KeyCode::Char('t' | 'T') => app.cycle_restore_target(),
+        KeyCode::Enter | KeyCode::Char('y' | 'Y') => app.confirm_restore(),
+        KeyCode::Esc | KeyCode::Char('n' | 'N' | 'q') => app.cancel_restore(),
+        _ => {}
+    }
+}
@@ -0,0 +1,16 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+
+/// Route a key press to the commit search prompt while it is being typed.
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.clear_commit_search(),
+        KeyCode::Enter => app.search_confirm(),
+        KeyCode::Backspace => app.search_backspace(),
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.search_input(c);
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,50 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+use crate::keymap::{Action, KeyChord};
+
+/// Route a key press to the open content viewer; commit stepping and a few file
+/// actions still go through the keymap so the viewer can follow history.
+pub fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
+    let selecting = app.viewer().is_some_and(|v| v.anchor.is_some());
+    match key.code {
+        KeyCode::Esc if selecting => app.toggle_viewer_anchor(),
+        KeyCode::Esc | KeyCode::Char('q') => app.close_viewer(),
+        KeyCode::Char('v' | 'V') => app.toggle_viewer_anchor(),
+        KeyCode::Enter => app.perform(Action::TraceLines)?,
+        KeyCode::Up | KeyCode::Char('k') => app.move_viewer_cursor(-1),
+        KeyCode::Down | KeyCode::Char('j') => app.move_viewer_cursor(1),
+        KeyCode::PageUp => app.move_viewer_cursor(-20),
+        KeyCode::PageDown => app.move_viewer_cursor(20),
+        KeyCode::Home => app.move_viewer_cursor(isize::MIN),
+        KeyCode::End => app.move_viewer_cursor(isize::MAX),
+        _ => {
+            let action = app.resolve_key(KeyChord::from_event(key));
+            match action {
+                Some(Action::ShowFile) => app.close_viewer(),
+                Some(
+                    action @ (Action::NextCommit
+                    | Action::PrevCommit
+                    | Action::NextTag
+                    | Action::PrevTag
+                    | Action::NextMatch
+                    | Action::PrevMatch
+                    | Action::TraceLines
+                    | Action::CommandPalette
+                    | Action::MarkBase
+                    | Action::TogglePlayback
+                    | Action::PlaybackFaster
+                    | Action::PlaybackSlower
+                    | Action::Restore
+                    | Action::OpenExternal
+                    | Action::Undo
+                    | Action::Help
+                    | Action::Quit),
+                ) => app.perform(action)?,
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
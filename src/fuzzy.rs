@@ -0,0 +1,62 @@
+/// A successful fuzzy match against one candidate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub text: String,
+    pub score: i64,
+    /// Character positions in `text` that matched the query.
+    pub positions: Vec<usize>,
+}
+
+/// Score a candidate against a query; `None` when not every query char is found in order.
+pub fn score(query: &str, candidate: &str) -> Option<Match> {
+    let mut query_chars = query.chars().map(|c| c.to_ascii_lowercase()).peekable();
+    let mut positions = Vec::new();
+    let mut score = 0i64;
+    let mut prev_char: Option<char> = None;
+
+    for (i, c) in candidate.chars().enumerate() {
+        let Some(&wanted) = query_chars.peek() else {
+            break;
+        };
+        if c.to_ascii_lowercase() == wanted {
+            score += match_bonus(prev_char, positions.last(), i);
+            positions.push(i);
+            query_chars.next();
+        }
+        prev_char = Some(c);
+    }
+
+    if query_chars.peek().is_some() {
+        return None;
+    }
+
+    let length_penalty = i64::try_from(candidate.len() / 8).unwrap_or(i64::MAX);
+    Some(Match {
+        text: candidate.to_string(),
+        score: score - length_penalty,
+        positions,
+    })
+}
+
+fn match_bonus(prev_char: Option<char>, prev_match: Option<&usize>, i: usize) -> i64 {
+    let mut bonus = 1;
+    if prev_match.is_some_and(|&p| p + 1 == i) {
+        bonus += 5;
+    }
+    if matches!(prev_char, None | Some('/' | '_' | '-' | '.' | ' ')) {
+        bonus += 3;
+    }
+    bonus
+}
+
+/// Match every candidate and return the hits, best first.
+pub fn rank<'a>(query: &str, candidates: impl Iterator<Item = &'a str>) -> Vec<Match> {
+    let mut matches: Vec<Match> = candidates.filter_map(|c| score(query, c)).collect();
+    matches.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.text.len().cmp(&b.text.len()))
+            .then_with(|| a.text.cmp(&b.text))
+    });
+    matches
+}
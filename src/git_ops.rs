@@ -1,8 +1,31 @@
+mod checkout;
+mod content;
+mod export;
+mod pickaxe;
+mod refs;
+mod report;
+mod restore;
+mod target;
+mod trace;
+
+#[cfg(test)]
+pub(crate) mod testing;
+
 use git2::{Oid, Repository, Sort, Tree};
 
 use crate::data::{Commit, FileHistory, History, Snapshot};
 use crate::error::{Result, TrekError};
 
+pub use checkout::{apply_checkout, plan_checkout, CheckoutPlan, PathChange};
+pub use content::{load_content, FileContent};
+pub use export::{remove_temp_copy, temp_copy};
+pub use pickaxe::{compile_needle, spawn_pickaxe, PickaxeEvent};
+pub use refs::{add_worktree, create_branch, create_tag, load_refs};
+pub use report::{tree_changes, TreeChanges};
+pub use restore::{apply_restore, plan_restore, undo_restore, RestorePlan, RestoreUndo};
+pub use target::{file_bytes, stage_files, write_copies, RestoreTarget};
+pub use trace::{find_function, trace_lines, LineRange};
+
 /// Find and open the git repository.
 pub fn find_repository() -> Result<Repository> {
     Repository::open_from_env().map_err(|_| TrekError::NoRepository)
@@ -23,6 +46,7 @@ pub fn load_history(repo: &Repository, limit: usize) -> Result<History> {
         collect_file_snapshots(repo, &commit.tree()?, idx, &mut history.files)?;
         history.commits.push(info);
     }
+    load_refs(repo, &mut history.commits)?;
 
     Ok(history)
 }
@@ -42,7 +66,11 @@ fn collect_commit_oids(repo: &Repository, limit: usize) -> Result<Vec<Oid>> {
 /// Build commit info from a git commit.
 fn build_commit_info(commit: &git2::Commit) -> Commit {
     let summary = commit.summary().unwrap_or("").to_string();
-    Commit::new(commit.id(), summary)
+    Commit {
+        author: commit.author().name().unwrap_or("").to_string(),
+        body: commit.body().unwrap_or("").to_string(),
+        ..Commit::new(commit.id(), summary, commit.time().seconds())
+    }
 }
 
 /// Collect file snapshots from a tree.
@@ -77,22 +105,3 @@ fn collect_file_snapshots(
 fn count_lines(content: &[u8]) -> usize {
     content.iter().filter(|&&c| c == b'\n').count()
 }
-
-/// Get file content at a specific commit.
-pub fn get_file_content(repo: &Repository, oid: Oid, path: &str) -> Result<String> {
-    let commit = repo.find_commit(oid)?;
-    let tree = commit.tree()?;
-    let entry = tree.get_path(std::path::Path::new(path))?;
-    let blob = repo.find_blob(entry.id())?;
-
-    std::str::from_utf8(blob.content())
-        .map(std::string::ToString::to_string)
-        .map_err(|_| TrekError::InvalidUtf8)
-}
-
-/// Restore a file from a specific commit to the working directory.
-pub fn restore_file(repo: &Repository, oid: Oid, path: &str) -> Result<()> {
-    let content = get_file_content(repo, oid, path)?;
-    std::fs::write(path, content)?;
-    Ok(())
-}
@@ -0,0 +1,158 @@
+use git2::build::CheckoutBuilder;
+use git2::{Delta, DiffOptions, Oid, Repository};
+
+use crate::error::{Result, TrekError};
+
+use super::restore::{
+    backup_dir, backup_file, is_uncommitted, read_working, RestoreUndo, SavedFile,
+};
+
+/// How restoring a set of paths changes one working copy file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathChange {
+    Modified,
+    /// Missing from the working copy; the restore brings it back.
+    Created,
+    /// Not in the commit; the restore deletes it.
+    Deleted,
+}
+
+/// What checking out files and directories from a commit would change.
+#[derive(Clone, Debug)]
+pub struct CheckoutPlan {
+    pub oid: Oid,
+    /// Selected files and directories; directories end in `/`, and `./` is the whole tree.
+    pub specs: Vec<String>,
+    pub changes: Vec<(String, PathChange)>,
+    /// How many of the changed files have uncommitted modifications.
+    pub uncommitted: usize,
+}
+
+impl CheckoutPlan {
+    pub fn count(&self, change: PathChange) -> usize {
+        self.changes.iter().filter(|(_, c)| *c == change).count()
+    }
+
+    /// Abbreviated sha of the commit being restored from, as shown elsewhere in the UI.
+    pub fn short_oid(&self) -> String {
+        self.oid.to_string().chars().take(8).collect()
+    }
+
+    /// One-line count of the changes, such as `8 modified, 2 created, 1 deleted`.
+    pub fn summary(&self) -> String {
+        let parts: Vec<String> = [
+            (PathChange::Modified, "modified"),
+            (PathChange::Created, "created"),
+            (PathChange::Deleted, "deleted"),
+        ]
+        .iter()
+        .map(|&(change, word)| (self.count(change), word))
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, word)| format!("{n} {word}"))
+        .collect();
+        parts.join(", ")
+    }
+}
+
+/// Compare the files under `specs` at `oid` with the working copy.
+pub fn plan_checkout(repo: &Repository, oid: Oid, specs: &[String]) -> Result<CheckoutPlan> {
+    if repo.is_bare() {
+        return Err(TrekError::BareRepository);
+    }
+    let tree = repo.find_commit(oid)?.tree()?;
+
+    let mut opts = DiffOptions::new();
+    opts.disable_pathspec_match(true);
+    // `./` stands for the whole tree, which an empty pathspec already means.
+    if !specs.iter().any(|s| s == "./") {
+        for spec in specs {
+            opts.pathspec(spec);
+        }
+    }
+    let diff = repo.diff_tree_to_workdir_with_index(Some(&tree), Some(&mut opts))?;
+
+    let changes: Vec<(String, PathChange)> = diff
+        .deltas()
+        .filter_map(|delta| {
+            let change = match delta.status() {
+                Delta::Added => PathChange::Deleted,
+                Delta::Deleted => PathChange::Created,
+                Delta::Unmodified | Delta::Ignored | Delta::Untracked => return None,
+                _ => PathChange::Modified,
+            };
+            let file = match change {
+                PathChange::Deleted => delta.new_file(),
+                _ => delta.old_file(),
+            };
+            let path = file.path()?.to_string_lossy().into_owned();
+            Some((path, change))
+        })
+        .collect();
+    let uncommitted = changes
+        .iter()
+        .filter(|(path, _)| is_uncommitted(repo, path))
+        .count();
+
+    Ok(CheckoutPlan {
+        oid,
+        specs: specs.to_vec(),
+        changes,
+        uncommitted,
+    })
+}
+
+/// Back up every file the plan touches, then check them all out from the commit in one go.
+pub fn apply_checkout(repo: &Repository, plan: &CheckoutPlan) -> Result<RestoreUndo> {
+    let workdir = repo.workdir().ok_or(TrekError::BareRepository)?;
+    let dir = backup_dir(repo);
+
+    let mut files = Vec::new();
+    let mut backed_up = false;
+    for (path, _) in &plan.changes {
+        let target = workdir.join(path);
+        let previous = read_working(&target)?;
+        if let Some(previous) = &previous {
+            backup_file(&dir, path, &previous.content)?;
+            backed_up = true;
+        }
+        files.push(SavedFile { target, previous });
+    }
+
+    let tree = repo.find_commit(plan.oid)?.tree()?;
+    let mut checkout = CheckoutBuilder::new();
+    checkout.force().update_index(false);
+    for (path, _) in &plan.changes {
+        checkout.path(escape_pathspec(path));
+    }
+    repo.checkout_tree(tree.as_object(), Some(&mut checkout))?;
+
+    // Checkout leaves files it does not know from HEAD, such as ones only staged.
+    for (path, change) in &plan.changes {
+        let target = workdir.join(path);
+        if *change == PathChange::Deleted && std::fs::symlink_metadata(&target).is_ok() {
+            std::fs::remove_file(&target)?;
+        }
+    }
+
+    Ok(RestoreUndo {
+        label: format!("{} files", plan.changes.len()),
+        files,
+        staged: Vec::new(),
+        backup: backed_up.then_some(dir),
+    })
+}
+
+/// Escape glob characters so a pathspec matches `path` literally.
+pub(super) fn escape_pathspec(path: &str) -> String {
+    let mut escaped = String::with_capacity(path.len());
+    for c in path.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,73 @@
+use super::*;
+use crate::git_ops::testing::{self, commit, read, write};
+
+#[test]
+fn escape_pathspec_makes_glob_characters_literal() {
+    assert_eq!(escape_pathspec("src/main.rs"), "src/main.rs");
+    assert_eq!(escape_pathspec("a*b?[c].txt"), "a\\*b\\?\\[c\\].txt");
+    assert_eq!(escape_pathspec("back\\slash"), "back\\\\slash");
+}
+
+#[test]
+fn plan_checkout_counts_files_with_uncommitted_changes() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    let old = commit(
+        &repo,
+        &[("src/a.rs", Some("a\n")), ("src/b.rs", Some("b\n"))],
+    )?;
+    commit(
+        &repo,
+        &[
+            ("src/a.rs", Some("a2\n")),
+            ("src/b.rs", None),
+            ("src/c.rs", Some("c\n")),
+        ],
+    )?;
+    write(&repo, "src/a.rs", "dirty\n")?;
+
+    let plan = plan_checkout(&repo, old, &["src/".to_string()])?;
+    let mut changes = plan.changes.clone();
+    changes.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        changes,
+        [
+            ("src/a.rs".to_string(), PathChange::Modified),
+            ("src/b.rs".to_string(), PathChange::Created),
+            ("src/c.rs".to_string(), PathChange::Deleted),
+        ]
+    );
+    assert_eq!(plan.uncommitted, 1);
+    assert_eq!(plan.summary(), "1 modified, 1 created, 1 deleted");
+    Ok(())
+}
+
+#[test]
+fn plan_checkout_of_a_clean_tree_at_head_changes_nothing() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    let head = commit(&repo, &[("a.txt", Some("a\n"))])?;
+    let plan = plan_checkout(&repo, head, &["./".to_string()])?;
+    assert!(plan.changes.is_empty());
+    assert_eq!(plan.uncommitted, 0);
+    Ok(())
+}
+
+#[test]
+fn apply_checkout_restores_literal_paths_and_undoes() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    let old = commit(&repo, &[("[x].txt", Some("old\n")), ("x.txt", Some("x\n"))])?;
+    commit(
+        &repo,
+        &[("[x].txt", Some("new\n")), ("x.txt", Some("x2\n"))],
+    )?;
+
+    let plan = plan_checkout(&repo, old, &["[x].txt".to_string()])?;
+    assert_eq!(plan.changes.len(), 1);
+    let undo = apply_checkout(&repo, &plan)?;
+    assert_eq!(read(&repo, "[x].txt")?, "old\n");
+    // A glob would also have matched x.txt.
+    assert_eq!(read(&repo, "x.txt")?, "x2\n");
+
+    crate::git_ops::undo_restore(&repo, &undo)?;
+    assert_eq!(read(&repo, "[x].txt")?, "new\n");
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use git2::{ErrorCode, Oid, Patch, Repository};
+
+use crate::error::{Result, TrekError};
+
+use super::restore::version_at;
+
+/// A file's text at one commit, with the lines that commit changed.
+#[derive(Clone, Debug, Default)]
+pub struct FileContent {
+    pub lines: Vec<String>,
+    /// Size in bytes when the file is binary; binary files have no lines.
+    pub binary: Option<usize>,
+    /// Zero-based lines added or rewritten since the base.
+    pub added: BTreeSet<usize>,
+    /// Base lines removed just above each zero-based line.
+    pub removed: BTreeMap<usize, Vec<String>>,
+}
+
+/// Load `path` as of `oid`, marking changes against `base`; `None` when the file is
+/// missing at `oid`. Without a base every line counts as added.
+pub fn load_content(
+    repo: &Repository,
+    oid: Oid,
+    base: Option<Oid>,
+    path: &str,
+) -> Result<Option<FileContent>> {
+    let version = match version_at(repo, oid, path) {
+        Ok(version) => version,
+        Err(TrekError::Git(e)) if e.code() == ErrorCode::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    if version.is_binary() {
+        return Ok(Some(FileContent {
+            binary: Some(version.content.len()),
+            ..FileContent::default()
+        }));
+    }
+
+    let old = base
+        .and_then(|base| version_at(repo, base, path).ok())
+        .map(|v| v.content)
+        .unwrap_or_default();
+    let mut content = FileContent {
+        lines: String::from_utf8_lossy(&version.content)
+            .lines()
+            .map(String::from)
+            .collect(),
+        ..FileContent::default()
+    };
+    mark_changes(&old, &version.content, &mut content)?;
+    Ok(Some(content))
+}
+
+fn mark_changes(old: &[u8], new: &[u8], content: &mut FileContent) -> Result<()> {
+    let patch = Patch::from_buffers(old, None, new, None, None)?;
+    for hunk in 0..patch.num_hunks() {
+        let (header, lines) = patch.hunk(hunk)?;
+        // Lines of the new file not yet passed in this hunk start here.
+        let mut next = (header.new_start() as usize).saturating_sub(1);
+        for i in 0..lines {
+            let line = patch.line_in_hunk(hunk, i)?;
+            match line.origin() {
+                '+' => {
+                    content.added.insert(next);
+                    next += 1;
+                }
+                '-' => {
+                    let text = String::from_utf8_lossy(line.content());
+                    content
+                        .removed
+                        .entry(next)
+                        .or_default()
+                        .push(text.trim_end_matches(['\n', '\r']).to_string());
+                }
+                ' ' => next += 1,
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
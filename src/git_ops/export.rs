@@ -0,0 +1,39 @@
+use std::path::{Path, PathBuf};
+
+use git2::{Oid, Repository};
+
+use crate::error::Result;
+
+use super::restore::version_at;
+
+/// Write `path` as of `oid` to a read-only temporary file that keeps the original
+/// file name, so editors and pagers still pick the right syntax.
+pub fn temp_copy(repo: &Repository, oid: Oid, path: &str) -> Result<PathBuf> {
+    let version = version_at(repo, oid, path)?;
+    let name = Path::new(path).file_name().unwrap_or(path.as_ref());
+    let short = oid.to_string().chars().take(8).collect::<String>();
+    let dir = std::env::temp_dir()
+        .join(format!("git-trek-{}", std::process::id()))
+        .join(short);
+    std::fs::create_dir_all(&dir)?;
+
+    let file = dir.join(name);
+    if file.exists() {
+        std::fs::remove_file(&file)?;
+    }
+    std::fs::write(&file, &version.content)?;
+    let mut permissions = std::fs::metadata(&file)?.permissions();
+    permissions.set_readonly(true);
+    std::fs::set_permissions(&file, permissions)?;
+    Ok(file)
+}
+
+/// Delete a copy made by [`temp_copy`] along with the directories it emptied.
+pub fn remove_temp_copy(file: &Path) {
+    let _ = std::fs::remove_file(file);
+    for dir in file.ancestors().skip(1).take(2) {
+        if std::fs::remove_dir(dir).is_err() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use git2::{DiffOptions, Oid, Repository};
+use regex::bytes::Regex;
+
+use crate::error::Result;
+
+use super::checkout::escape_pathspec;
+
+/// Progress reported by a background pickaxe scan.
+#[derive(Debug)]
+pub enum PickaxeEvent {
+    /// A commit changed the number of occurrences in these paths.
+    Hit {
+        commit_idx: usize,
+        paths: Vec<String>,
+    },
+    Scanned(usize),
+    Done,
+    Failed(String),
+}
+
+/// Compile a pickaxe query: `/regex/` or a literal string.
+pub fn compile_needle(query: &str) -> Result<Regex> {
+    let pattern = match query.strip_prefix('/').and_then(|q| q.strip_suffix('/')) {
+        Some(re) if !re.is_empty() => re.to_string(),
+        _ => regex::escape(query),
+    };
+    Ok(Regex::new(&pattern)?)
+}
+
+/// Scan commit diffs on a background thread for changes in occurrences of `needle`.
+pub fn spawn_pickaxe(
+    repo_path: PathBuf,
+    commits: Vec<Oid>,
+    needle: Regex,
+    scope: Option<String>,
+) -> Receiver<PickaxeEvent> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let outcome = Repository::open(&repo_path)
+            .map_err(crate::error::TrekError::from)
+            .and_then(|repo| scan(&repo, &commits, &needle, scope.as_deref(), &tx));
+        let last = match outcome {
+            Ok(()) => PickaxeEvent::Done,
+            Err(e) => PickaxeEvent::Failed(e.to_string()),
+        };
+        let _ = tx.send(last);
+    });
+    rx
+}
+
+fn scan(
+    repo: &Repository,
+    commits: &[Oid],
+    needle: &Regex,
+    scope: Option<&str>,
+    tx: &Sender<PickaxeEvent>,
+) -> Result<()> {
+    for (commit_idx, oid) in commits.iter().enumerate() {
+        let paths = changed_paths(repo, *oid, needle, scope)?;
+        if !paths.is_empty() && tx.send(PickaxeEvent::Hit { commit_idx, paths }).is_err() {
+            return Ok(());
+        }
+        if tx.send(PickaxeEvent::Scanned(commit_idx + 1)).is_err() {
+            return Ok(());
+        }
+    }
+    Ok(())
+}
+
+/// Paths whose occurrence count of `needle` differs between a commit and its first parent.
+fn changed_paths(
+    repo: &Repository,
+    oid: Oid,
+    needle: &Regex,
+    scope: Option<&str>,
+) -> Result<Vec<String>> {
+    let commit = repo.find_commit(oid)?;
+    let tree = commit.tree()?;
+    let parent_tree = match commit.parents().next() {
+        Some(parent) => Some(parent.tree()?),
+        None => None,
+    };
+
+    let mut opts = DiffOptions::new();
+    if let Some(path) = scope {
+        opts.pathspec(escape_pathspec(path));
+    }
+    let diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut opts))?;
+
+    let mut paths = Vec::new();
+    for delta in diff.deltas() {
+        let before = count_in_blob(repo, delta.old_file().id(), needle);
+        let after = count_in_blob(repo, delta.new_file().id(), needle);
+        if before != after {
+            let file = delta.new_file().path().or_else(|| delta.old_file().path());
+            if let Some(path) = file {
+                paths.push(path.to_string_lossy().into_owned());
+            }
+        }
+    }
+    Ok(paths)
+}
+
+fn count_in_blob(repo: &Repository, id: Oid, needle: &Regex) -> usize {
+    if id.is_zero() {
+        return 0;
+    }
+    repo.find_blob(id)
+        .map_or(0, |blob| needle.find_iter(blob.content()).count())
+}
+
+#[cfg(test)]
+mod tests;
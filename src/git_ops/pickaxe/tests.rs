@@ -0,0 +1,23 @@
+use super::*;
+use crate::git_ops::testing::{self, commit};
+
+#[test]
+fn changed_paths_scope_matches_the_path_literally() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    commit(&repo, &[("a[1].txt", Some("x\n")), ("a1.txt", Some("x\n"))])?;
+    let oid = commit(
+        &repo,
+        &[
+            ("a[1].txt", Some("x\nneedle\n")),
+            ("a1.txt", Some("x\nneedle\n")),
+        ],
+    )?;
+    let needle = compile_needle("needle")?;
+
+    let scoped = changed_paths(&repo, oid, &needle, Some("a[1].txt"))?;
+    assert_eq!(scoped, vec!["a[1].txt".to_string()]);
+    let mut all = changed_paths(&repo, oid, &needle, None)?;
+    all.sort();
+    assert_eq!(all, vec!["a1.txt".to_string(), "a[1].txt".to_string()]);
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use git2::{BranchType, Oid, ReferenceType, Repository, WorktreeAddOptions};
+
+use crate::data::{Commit, RefKind, RefLabel};
+use crate::error::Result;
+
+/// Attach HEAD and every local branch, remote branch and tag to the commit it
+/// points at.
+pub fn load_refs(repo: &Repository, commits: &mut [Commit]) -> Result<()> {
+    let positions: HashMap<Oid, usize> = commits
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (c.oid, i))
+        .collect();
+    for commit in commits.iter_mut() {
+        commit.refs.clear();
+    }
+
+    let mut labels = Vec::new();
+    if let Ok(head) = repo.head().and_then(|h| h.peel_to_commit()) {
+        labels.push((head.id(), RefKind::Head, "HEAD".to_string()));
+    }
+    for reference in repo.references()?.flatten() {
+        // Symbolic refs such as `origin/HEAD` repeat the branch they point to.
+        if reference.kind() != Some(ReferenceType::Direct) {
+            continue;
+        }
+        let kind = if reference.is_tag() {
+            RefKind::Tag
+        } else if reference.is_remote() {
+            RefKind::Remote
+        } else if reference.is_branch() {
+            RefKind::Branch
+        } else {
+            continue;
+        };
+        let (Some(name), Ok(target)) = (reference.shorthand(), reference.peel_to_commit()) else {
+            continue;
+        };
+        labels.push((target.id(), kind, name.to_string()));
+    }
+
+    for (oid, kind, name) in labels {
+        if let Some(commit) = positions.get(&oid).and_then(|&idx| commits.get_mut(idx)) {
+            commit.refs.push(RefLabel { kind, name });
+        }
+    }
+    for commit in commits.iter_mut() {
+        commit
+            .refs
+            .sort_by(|a, b| (a.kind, &a.name).cmp(&(b.kind, &b.name)));
+    }
+    Ok(())
+}
+
+/// Create the local branch `name` at commit `oid`, refusing to move an existing one.
+pub fn create_branch(repo: &Repository, oid: Oid, name: &str) -> Result<()> {
+    let commit = repo.find_commit(oid)?;
+    repo.branch(name, &commit, false)?;
+    Ok(())
+}
+
+/// Tag commit `oid` as `name`; a message makes it an annotated tag signed by the
+/// configured user.
+pub fn create_tag(repo: &Repository, oid: Oid, name: &str, message: Option<&str>) -> Result<()> {
+    let target = repo.find_object(oid, None)?;
+    match message {
+        Some(message) => {
+            let tagger = repo.signature()?;
+            repo.tag(name, &target, &tagger, message, false)?;
+        }
+        None => {
+            repo.tag_lightweight(name, &target, false)?;
+        }
+    }
+    Ok(())
+}
+
+/// Add a linked worktree at `path` with the new branch `branch` checked out at
+/// commit `oid`.
+pub fn add_worktree(repo: &Repository, oid: Oid, path: &Path, branch: &str) -> Result<()> {
+    let commit = repo.find_commit(oid)?;
+    let created = repo.branch(branch, &commit, false)?;
+    let reference = created.into_reference();
+    // Worktree names live under .git/worktrees, so they cannot nest.
+    let name = branch.replace('/', "-");
+    let mut options = WorktreeAddOptions::new();
+    options.reference(Some(&reference));
+    if let Err(err) = repo.worktree(&name, path, Some(&options)) {
+        // Leave no stray branch behind when the worktree could not be added.
+        if let Ok(mut created) = repo.find_branch(branch, BranchType::Local) {
+            created.delete().ok();
+        }
+        return Err(err.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use git2::{Delta, DiffFindOptions, Oid, Repository};
+
+use crate::error::Result;
+
+/// Files that appeared, disappeared or moved between two commits.
+#[derive(Clone, Debug, Default)]
+pub struct TreeChanges {
+    pub added: Vec<String>,
+    pub deleted: Vec<String>,
+    /// `(old path, new path)` pairs found by content similarity.
+    pub renamed: Vec<(String, String)>,
+}
+
+/// Compare the trees of `from` and `to`, pairing deletions with additions that
+/// have similar content as renames, like `git diff -M`.
+pub fn tree_changes(repo: &Repository, from: Oid, to: Oid) -> Result<TreeChanges> {
+    let old = repo.find_commit(from)?.tree()?;
+    let new = repo.find_commit(to)?.tree()?;
+    let mut diff = repo.diff_tree_to_tree(Some(&old), Some(&new), None)?;
+    diff.find_similar(Some(DiffFindOptions::new().renames(true)))?;
+
+    let mut changes = TreeChanges::default();
+    for delta in diff.deltas() {
+        let path = |file: git2::DiffFile| {
+            file.path()
+                .map(|p| p.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        };
+        match delta.status() {
+            Delta::Added => changes.added.push(path(delta.new_file())),
+            Delta::Deleted => changes.deleted.push(path(delta.old_file())),
+            Delta::Renamed => changes
+                .renamed
+                .push((path(delta.old_file()), path(delta.new_file()))),
+            _ => {}
+        }
+    }
+    Ok(changes)
+}
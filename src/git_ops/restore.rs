@@ -0,0 +1,317 @@
+use std::fs::Metadata;
+use std::path::{Path, PathBuf};
+
+use git2::{FileMode, IndexEntry, Oid, Patch, Repository, Status};
+
+use crate::error::{Result, TrekError};
+
+/// How a file is stored: the parts of a git filemode that a restore must keep.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileKind {
+    Regular,
+    Executable,
+    /// The content is the link target.
+    Symlink,
+}
+
+impl FileKind {
+    fn from_filemode(mode: i32) -> Self {
+        if mode == i32::from(FileMode::Link) {
+            Self::Symlink
+        } else if mode == i32::from(FileMode::BlobExecutable) {
+            Self::Executable
+        } else {
+            Self::Regular
+        }
+    }
+
+    fn from_metadata(meta: &Metadata) -> Self {
+        if meta.file_type().is_symlink() {
+            return Self::Symlink;
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if meta.permissions().mode() & 0o111 != 0 {
+                return Self::Executable;
+            }
+        }
+        Self::Regular
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Regular => "regular file",
+            Self::Executable => "executable",
+            Self::Symlink => "symlink",
+        }
+    }
+}
+
+/// Raw content of one version of a file, with how it is stored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileVersion {
+    pub content: Vec<u8>,
+    pub kind: FileKind,
+}
+
+impl FileVersion {
+    /// Git treats content with a NUL byte as binary.
+    pub fn is_binary(&self) -> bool {
+        self.content.contains(&0)
+    }
+}
+
+/// What restoring a file from a commit would change, worked out before anything is written.
+#[derive(Clone, Debug)]
+pub struct RestorePlan {
+    /// Path relative to the repository root.
+    pub path: String,
+    /// Where the file lives in the working tree.
+    pub target: PathBuf,
+    pub oid: Oid,
+    pub incoming: FileVersion,
+    /// Working copy version that would be overwritten, or `None` if the file is missing.
+    pub current: Option<FileVersion>,
+    /// Lines added and removed relative to the working copy.
+    pub added: usize,
+    pub removed: usize,
+    /// The working copy has changes that are not committed.
+    pub uncommitted: bool,
+}
+
+impl RestorePlan {
+    /// Whether the working copy already holds the version being restored.
+    pub fn is_noop(&self) -> bool {
+        self.current.as_ref() == Some(&self.incoming)
+    }
+
+    /// Whether either side is binary, so line counts mean nothing.
+    pub fn is_binary(&self) -> bool {
+        self.incoming.is_binary() || self.current.as_ref().is_some_and(FileVersion::is_binary)
+    }
+
+    /// Abbreviated sha of the commit being restored from, as shown elsewhere in the UI.
+    pub fn short_oid(&self) -> String {
+        self.oid.to_string().chars().take(8).collect()
+    }
+}
+
+/// A working copy file as it was before a restore wrote over it.
+#[derive(Clone, Debug)]
+pub struct SavedFile {
+    pub target: PathBuf,
+    /// `None` if the restore created the file.
+    pub previous: Option<FileVersion>,
+}
+
+/// An index entry as it was before a restore staged over it.
+#[derive(Debug)]
+pub struct SavedEntry {
+    pub path: String,
+    /// `None` if the path was not staged.
+    pub previous: Option<IndexEntry>,
+}
+
+/// What a restore replaced, kept so it can be undone.
+#[derive(Debug)]
+pub struct RestoreUndo {
+    /// What was restored, for messages: a path or a file count.
+    pub label: String,
+    pub files: Vec<SavedFile>,
+    pub staged: Vec<SavedEntry>,
+    /// Where the overwritten content was backed up, if anything was.
+    pub backup: Option<PathBuf>,
+}
+
+/// Compare the version of `path` at `oid` with the working copy.
+pub fn plan_restore(repo: &Repository, oid: Oid, path: &str) -> Result<RestorePlan> {
+    let workdir = repo.workdir().ok_or(TrekError::BareRepository)?;
+    let target = workdir.join(path);
+    let incoming = version_at(repo, oid, path)?;
+    let current = read_working(&target)?;
+
+    let (added, removed) = line_changes(
+        current.as_ref().map_or(&[][..], |c| &c.content),
+        &incoming.content,
+    )?;
+
+    let uncommitted = is_uncommitted(repo, path);
+
+    Ok(RestorePlan {
+        path: path.to_string(),
+        target,
+        oid,
+        incoming,
+        current,
+        added,
+        removed,
+        uncommitted,
+    })
+}
+
+/// Write the planned version, first saving the overwritten file under `.git/git-trek/backups/`.
+pub fn apply_restore(repo: &Repository, plan: &RestorePlan) -> Result<RestoreUndo> {
+    let backup = match &plan.current {
+        Some(previous) => Some(backup_file(
+            &backup_dir(repo),
+            &plan.path,
+            &previous.content,
+        )?),
+        None => None,
+    };
+    write_version(&plan.target, &plan.incoming)?;
+    Ok(RestoreUndo {
+        label: plan.path.clone(),
+        files: vec![SavedFile {
+            target: plan.target.clone(),
+            previous: plan.current.clone(),
+        }],
+        staged: Vec::new(),
+        backup,
+    })
+}
+
+/// Put back the versions a restore replaced, removing files that did not exist before.
+pub fn undo_restore(repo: &Repository, undo: &RestoreUndo) -> Result<()> {
+    if !undo.staged.is_empty() {
+        let mut index = repo.index()?;
+        for entry in &undo.staged {
+            match &entry.previous {
+                Some(previous) => index.add(previous)?,
+                None => index.remove_path(Path::new(&entry.path))?,
+            }
+        }
+        index.write()?;
+    }
+    for file in &undo.files {
+        match &file.previous {
+            Some(previous) => write_version(&file.target, previous)?,
+            None if std::fs::symlink_metadata(&file.target).is_ok() => {
+                std::fs::remove_file(&file.target)?;
+            }
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+/// Whether the working copy of `path` differs from what is committed.
+pub(super) fn is_uncommitted(repo: &Repository, path: &str) -> bool {
+    repo.status_file(Path::new(path))
+        .is_ok_and(|status| status != Status::CURRENT && !status.is_ignored())
+}
+
+/// Raw content and mode of `path` in the tree of commit `oid`.
+pub(super) fn version_at(repo: &Repository, oid: Oid, path: &str) -> Result<FileVersion> {
+    let tree = repo.find_commit(oid)?.tree()?;
+    let entry = tree.get_path(Path::new(path))?;
+    let blob = repo.find_blob(entry.id())?;
+    Ok(FileVersion {
+        content: blob.content().to_vec(),
+        kind: FileKind::from_filemode(entry.filemode()),
+    })
+}
+
+/// The working copy version at `target`, without following a symlink.
+pub(super) fn read_working(target: &Path) -> Result<Option<FileVersion>> {
+    let meta = match std::fs::symlink_metadata(target) {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let kind = FileKind::from_metadata(&meta);
+    let content = if kind == FileKind::Symlink {
+        std::fs::read_link(target)?
+            .to_string_lossy()
+            .into_owned()
+            .into_bytes()
+    } else {
+        std::fs::read(target)?
+    };
+    Ok(Some(FileVersion { content, kind }))
+}
+
+/// Lines added and removed going from `old` to `new`.
+fn line_changes(old: &[u8], new: &[u8]) -> Result<(usize, usize)> {
+    let diff = Patch::from_buffers(old, None, new, None, None)?;
+    let (_, added, removed) = diff.line_stats()?;
+    Ok((added, removed))
+}
+
+/// Fresh `.git/git-trek/backups/<timestamp>/` directory for one restore, numbered
+/// `<timestamp>-2`, `-3`, ... when an earlier restore in the same second took it.
+pub(super) fn backup_dir(repo: &Repository) -> PathBuf {
+    let root = repo.path().join("git-trek").join("backups");
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+    let mut dir = root.join(&stamp);
+    let mut n = 1;
+    while dir.exists() {
+        n += 1;
+        dir = root.join(format!("{stamp}-{n}"));
+    }
+    dir
+}
+
+/// Copy `content` to `path` inside the backup directory `dir`.
+pub(super) fn backup_file(dir: &Path, path: &str, content: &[u8]) -> Result<PathBuf> {
+    let backup = dir.join(path);
+    create_parent(&backup)?;
+    std::fs::write(&backup, content)?;
+    Ok(backup)
+}
+
+/// Replace whatever is at `target` with `version`, as git checkout would.
+pub(super) fn write_version(target: &Path, version: &FileVersion) -> Result<()> {
+    create_parent(target)?;
+    // Writing through an existing symlink would change the file it points to,
+    // and a new symlink cannot be created over an existing file.
+    let existing = std::fs::symlink_metadata(target).ok();
+    if existing.is_some_and(|m| m.file_type().is_symlink() || version.kind == FileKind::Symlink) {
+        std::fs::remove_file(target)?;
+    }
+
+    match version.kind {
+        #[cfg(unix)]
+        FileKind::Symlink => {
+            let link = String::from_utf8_lossy(&version.content).into_owned();
+            std::os::unix::fs::symlink(link, target)?;
+        }
+        _ => {
+            std::fs::write(target, &version.content)?;
+            set_executable(target, version.kind == FileKind::Executable)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn set_executable(target: &Path, executable: bool) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut perms = std::fs::metadata(target)?.permissions();
+    let mode = perms.mode();
+    // Grant execute wherever read is granted, like git does for 100755 entries.
+    let mode = if executable {
+        mode | ((mode & 0o444) >> 2)
+    } else {
+        mode & !0o111
+    };
+    perms.set_mode(mode);
+    std::fs::set_permissions(target, perms)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn set_executable(_target: &Path, _executable: bool) -> Result<()> {
+    Ok(())
+}
+
+fn create_parent(path: &Path) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
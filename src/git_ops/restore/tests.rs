@@ -0,0 +1,105 @@
+use super::*;
+use crate::error::Result;
+use crate::git_ops::testing::{self, commit, read, write};
+
+#[test]
+fn undo_puts_back_the_overwritten_working_copy() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    let old = commit(&repo, &[("a.txt", Some("one\n"))])?;
+    commit(&repo, &[("a.txt", Some("two\n"))])?;
+    write(&repo, "a.txt", "dirty\n")?;
+
+    let plan = plan_restore(&repo, old, "a.txt")?;
+    assert!(plan.uncommitted);
+    let undo = apply_restore(&repo, &plan)?;
+    assert_eq!(read(&repo, "a.txt")?, "one\n");
+    let backup = undo
+        .backup
+        .as_deref()
+        .map(std::fs::read_to_string)
+        .transpose()?;
+    assert_eq!(backup.as_deref(), Some("dirty\n"));
+
+    undo_restore(&repo, &undo)?;
+    assert_eq!(read(&repo, "a.txt")?, "dirty\n");
+    Ok(())
+}
+
+#[test]
+fn undo_removes_a_file_the_restore_created() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    let old = commit(&repo, &[("a.txt", Some("one\n"))])?;
+    commit(&repo, &[("a.txt", None), ("b.txt", Some("b\n"))])?;
+
+    let plan = plan_restore(&repo, old, "a.txt")?;
+    assert!(plan.current.is_none());
+    let undo = apply_restore(&repo, &plan)?;
+    assert_eq!(read(&repo, "a.txt")?, "one\n");
+    assert!(undo.backup.is_none());
+
+    undo_restore(&repo, &undo)?;
+    assert!(!plan.target.exists());
+    Ok(())
+}
+
+#[test]
+fn backup_dirs_are_not_reused() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    let first = backup_dir(&repo);
+    std::fs::create_dir_all(&first)?;
+    let second = backup_dir(&repo);
+    assert_ne!(first, second);
+    assert!(!second.exists());
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn write_version_sets_and_clears_the_executable_bit() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = tempfile::tempdir()?;
+    let target = dir.path().join("run.sh");
+    let mode = || -> Result<u32> { Ok(std::fs::metadata(&target)?.permissions().mode() & 0o777) };
+
+    let script = |kind| FileVersion {
+        content: b"#!/bin/sh\n".to_vec(),
+        kind,
+    };
+    write_version(&target, &script(FileKind::Executable))?;
+    assert_eq!(mode()? & 0o111, (mode()? & 0o444) >> 2);
+    assert_eq!(
+        read_working(&target)?.map(|v| v.kind),
+        Some(FileKind::Executable)
+    );
+
+    write_version(&target, &script(FileKind::Regular))?;
+    assert_eq!(mode()? & 0o111, 0);
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn write_version_replaces_a_symlink_instead_of_writing_through_it() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let pointee = dir.path().join("real.txt");
+    let link = dir.path().join("link");
+    std::fs::write(&pointee, "real\n")?;
+    std::os::unix::fs::symlink("real.txt", &link)?;
+
+    let regular = FileVersion {
+        content: b"restored\n".to_vec(),
+        kind: FileKind::Regular,
+    };
+    write_version(&link, &regular)?;
+    assert!(!std::fs::symlink_metadata(&link)?.file_type().is_symlink());
+    assert_eq!(std::fs::read_to_string(&pointee)?, "real\n");
+
+    let symlink = FileVersion {
+        content: b"real.txt".to_vec(),
+        kind: FileKind::Symlink,
+    };
+    write_version(&link, &symlink)?;
+    assert_eq!(std::fs::read_link(&link)?, Path::new("real.txt"));
+    assert_eq!(read_working(&link)?, Some(symlink));
+    Ok(())
+}
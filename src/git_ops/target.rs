@@ -0,0 +1,158 @@
+use std::path::{Path, PathBuf};
+
+use git2::{IndexEntry, IndexTime, Oid, Repository};
+
+use crate::error::{Result, TrekError};
+
+use super::restore::{
+    backup_dir, backup_file, read_working, version_at, write_version, RestoreUndo, SavedEntry,
+    SavedFile,
+};
+
+/// Where a restore writes the historical version.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RestoreTarget {
+    /// Overwrite the working copy.
+    #[default]
+    Worktree,
+    /// Stage the version without touching the working copy.
+    Index,
+    /// Write `<path>.<shortsha>` next to the current file.
+    Side,
+    /// Write to a path the user chooses.
+    Path,
+    /// Print the content when git-trek exits.
+    Stdout,
+}
+
+impl RestoreTarget {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "worktree" => Some(Self::Worktree),
+            "index" => Some(Self::Index),
+            "side" => Some(Self::Side),
+            "path" => Some(Self::Path),
+            "stdout" => Some(Self::Stdout),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Worktree => "working tree",
+            Self::Index => "index",
+            Self::Side => "side copy",
+            Self::Path => "chosen path",
+            Self::Stdout => "stdout",
+        }
+    }
+
+    /// The next target in the dialog's cycle; stdout only takes a single file.
+    #[must_use]
+    pub fn next(self, single_file: bool) -> Self {
+        match self {
+            Self::Worktree => Self::Index,
+            Self::Index => Self::Side,
+            Self::Side => Self::Path,
+            Self::Path if single_file => Self::Stdout,
+            Self::Path | Self::Stdout => Self::Worktree,
+        }
+    }
+}
+
+/// Write the version of each `(path, destination)` at `oid`, backing up anything overwritten.
+pub fn write_copies(
+    repo: &Repository,
+    oid: Oid,
+    copies: &[(String, PathBuf)],
+    label: String,
+) -> Result<RestoreUndo> {
+    let dir = backup_dir(repo);
+    let mut files = Vec::new();
+    let mut backed_up = false;
+    for (path, dest) in copies {
+        let version = version_at(repo, oid, path)?;
+        let previous = read_working(dest)?;
+        if let Some(previous) = &previous {
+            backup_file(&dir, path, &previous.content)?;
+            backed_up = true;
+        }
+        write_version(dest, &version)?;
+        files.push(SavedFile {
+            target: dest.clone(),
+            previous,
+        });
+    }
+    Ok(RestoreUndo {
+        label,
+        files,
+        staged: Vec::new(),
+        backup: backed_up.then_some(dir),
+    })
+}
+
+/// Stage the version of each path at `oid`, unstaging paths the commit does not have.
+pub fn stage_files(
+    repo: &Repository,
+    oid: Oid,
+    paths: &[String],
+    label: String,
+) -> Result<RestoreUndo> {
+    if repo.is_bare() {
+        return Err(TrekError::BareRepository);
+    }
+    let tree = repo.find_commit(oid)?.tree()?;
+    let mut index = repo.index()?;
+    let mut staged = Vec::new();
+
+    for path in paths {
+        let previous = index.get_path(Path::new(path), 0);
+        match tree.get_path(Path::new(path)) {
+            Ok(entry) => {
+                let size = repo.find_blob(entry.id())?.size();
+                index.add(&index_entry(path, entry.id(), entry.filemode(), size))?;
+            }
+            Err(e) if e.code() == git2::ErrorCode::NotFound => {
+                if previous.is_some() {
+                    index.remove_path(Path::new(path))?;
+                }
+            }
+            Err(e) => return Err(e.into()),
+        }
+        staged.push(SavedEntry {
+            path: path.clone(),
+            previous,
+        });
+    }
+    index.write()?;
+
+    Ok(RestoreUndo {
+        label,
+        files: Vec::new(),
+        staged,
+        backup: None,
+    })
+}
+
+/// Raw content of `path` at `oid`.
+pub fn file_bytes(repo: &Repository, oid: Oid, path: &str) -> Result<Vec<u8>> {
+    Ok(version_at(repo, oid, path)?.content)
+}
+
+/// A fresh index entry; zeroed stat data makes git re-read the file on the next status.
+fn index_entry(path: &str, id: Oid, mode: i32, size: usize) -> IndexEntry {
+    IndexEntry {
+        ctime: IndexTime::new(0, 0),
+        mtime: IndexTime::new(0, 0),
+        dev: 0,
+        ino: 0,
+        mode: u32::try_from(mode).unwrap_or_default(),
+        uid: 0,
+        gid: 0,
+        file_size: u32::try_from(size).unwrap_or(u32::MAX),
+        id,
+        flags: u16::try_from(path.len()).unwrap_or(0xfff).min(0xfff),
+        flags_extended: 0,
+        path: path.as_bytes().to_vec(),
+    }
+}
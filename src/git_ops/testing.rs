@@ -0,0 +1,65 @@
+use std::path::Path;
+
+use git2::{Oid, Repository, Signature};
+use tempfile::TempDir;
+
+use crate::error::Result;
+
+/// A new repository in a temporary directory, removed when the directory is dropped.
+pub fn init() -> Result<(TempDir, Repository)> {
+    let dir = tempfile::tempdir()?;
+    let repo = Repository::init(dir.path())?;
+    Ok((dir, repo))
+}
+
+/// Write `files` into the working tree, removing those given as `None`, and commit
+/// everything on top of HEAD.
+pub fn commit(repo: &Repository, files: &[(&str, Option<&str>)]) -> Result<Oid> {
+    commit_as(repo, "Test", files)
+}
+
+/// [`commit`] with `author` as the author and committer name.
+pub fn commit_as(repo: &Repository, author: &str, files: &[(&str, Option<&str>)]) -> Result<Oid> {
+    for &(path, content) in files {
+        match content {
+            Some(content) => write(repo, path, content)?,
+            None => std::fs::remove_file(workdir(repo).join(path))?,
+        }
+    }
+
+    let mut index = repo.index()?;
+    index.add_all(["*"], git2::IndexAddOption::DEFAULT, None)?;
+    index.update_all(["*"], None)?;
+    index.write()?;
+    let tree = repo.find_tree(index.write_tree()?)?;
+    let signature = Signature::now(author, "test@example.com")?;
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    Ok(repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        "commit",
+        &tree,
+        &parents,
+    )?)
+}
+
+/// Write `content` to `path` in the working tree without committing it.
+pub fn write(repo: &Repository, path: &str, content: &str) -> Result<()> {
+    let target = workdir(repo).join(path);
+    if let Some(dir) = target.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(target, content)?;
+    Ok(())
+}
+
+/// Content of `path` in the working tree.
+pub fn read(repo: &Repository, path: &str) -> Result<String> {
+    Ok(std::fs::read_to_string(workdir(repo).join(path))?)
+}
+
+pub fn workdir(repo: &Repository) -> &Path {
+    repo.workdir().unwrap_or_else(|| repo.path())
+}
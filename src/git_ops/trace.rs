@@ -0,0 +1,242 @@
+use std::collections::{BTreeMap, HashMap};
+
+use git2::{DiffOptions, Oid, Patch, Repository};
+
+use crate::error::Result;
+
+use super::restore::version_at;
+
+/// A line range, one-based and inclusive.
+pub type LineRange = (usize, usize);
+
+/// How a line range moved back through history, like `git log -L`.
+#[derive(Clone, Debug, Default)]
+pub struct TraceResult {
+    /// Where the range sat at each commit walked, by commit index.
+    pub ranges: BTreeMap<usize, LineRange>,
+    /// Commits that changed lines in the range, newest first.
+    pub commits: Vec<usize>,
+}
+
+/// A changed block from a diff without context lines.
+struct Hunk {
+    old_start: usize,
+    old_lines: usize,
+    new_start: usize,
+    new_lines: usize,
+}
+
+/// Follow `range` of `path` from the commit at `from` along first parents, as far as
+/// the loaded commits `oids` go or until the lines were first written.
+pub fn trace_lines(
+    repo: &Repository,
+    oids: &[Oid],
+    from: usize,
+    path: &str,
+    range: LineRange,
+) -> Result<TraceResult> {
+    let positions: HashMap<Oid, usize> = oids.iter().enumerate().map(|(i, o)| (*o, i)).collect();
+    let mut result = TraceResult::default();
+    let mut idx = from;
+    let mut range = range;
+
+    while let Some(&oid) = oids.get(idx) {
+        result.ranges.insert(idx, range);
+        let commit = repo.find_commit(oid)?;
+        let Ok(new) = version_at(repo, oid, path).map(|v| v.content) else {
+            break;
+        };
+        let parent = commit.parent_ids().next();
+        let old = match parent {
+            Some(parent) => version_at(repo, parent, path).ok().map(|v| v.content),
+            None => None,
+        };
+        let Some(old) = old else {
+            // The file, and so the range, was created here.
+            result.commits.push(idx);
+            break;
+        };
+
+        let hunks = hunks(&old, &new)?;
+        if hunks.iter().any(|h| h.touches(range)) {
+            result.commits.push(idx);
+        }
+        let old_range = (map_start(&hunks, range.0), map_end(&hunks, range.1));
+        let parent_idx = parent.and_then(|p| positions.get(&p).copied());
+        match parent_idx {
+            Some(parent_idx) if old_range.0 <= old_range.1 => {
+                idx = parent_idx;
+                range = old_range;
+            }
+            _ => break,
+        }
+    }
+    Ok(result)
+}
+
+fn hunks(old: &[u8], new: &[u8]) -> Result<Vec<Hunk>> {
+    let mut options = DiffOptions::new();
+    options.context_lines(0);
+    let patch = Patch::from_buffers(old, None, new, None, Some(&mut options))?;
+    let mut hunks = Vec::with_capacity(patch.num_hunks());
+    for i in 0..patch.num_hunks() {
+        let (hunk, _) = patch.hunk(i)?;
+        hunks.push(Hunk {
+            old_start: hunk.old_start() as usize,
+            old_lines: hunk.old_lines() as usize,
+            new_start: hunk.new_start() as usize,
+            new_lines: hunk.new_lines() as usize,
+        });
+    }
+    Ok(hunks)
+}
+
+impl Hunk {
+    /// Last new line at or before the change; a pure deletion follows `new_start`.
+    fn new_end(&self) -> usize {
+        if self.new_lines == 0 {
+            self.new_start
+        } else {
+            self.new_start + self.new_lines - 1
+        }
+    }
+
+    /// Whether the hunk adds or rewrites lines in `range`, or deletes lines inside it.
+    fn touches(&self, (start, end): LineRange) -> bool {
+        if self.new_lines == 0 {
+            self.new_start >= start && self.new_start < end
+        } else {
+            self.new_start <= end && self.new_end() >= start
+        }
+    }
+}
+
+/// Old line where the new line `line` starts; a line added by a hunk maps to the
+/// first old line that hunk replaced.
+fn map_start(hunks: &[Hunk], line: usize) -> usize {
+    map_line(hunks, line, |h| {
+        if h.old_lines == 0 {
+            h.old_start + 1
+        } else {
+            h.old_start
+        }
+    })
+}
+
+/// Old line where the new line `line` ends; a line added by a hunk maps to the last
+/// old line that hunk replaced.
+fn map_end(hunks: &[Hunk], line: usize) -> usize {
+    map_line(hunks, line, |h| {
+        if h.old_lines == 0 {
+            h.old_start
+        } else {
+            h.old_start + h.old_lines - 1
+        }
+    })
+}
+
+fn map_line(hunks: &[Hunk], line: usize, inside: impl Fn(&Hunk) -> usize) -> usize {
+    let mut shift: isize = 0;
+    for hunk in hunks {
+        if hunk.new_end() < line {
+            shift += hunk.old_lines.cast_signed() - hunk.new_lines.cast_signed();
+        } else if hunk.new_lines > 0 && hunk.new_start <= line {
+            return inside(hunk);
+        } else {
+            break;
+        }
+    }
+    line.saturating_add_signed(shift)
+}
+
+/// Lines of the definition named `name`: from the first line that defines it to its
+/// closing brace, or to the end of its indented block when it has no braces.
+pub fn find_function(lines: &[String], name: &str) -> Option<LineRange> {
+    let start = lines.iter().position(|line| defines(line, name))?;
+    let end = block_end(lines, start);
+    Some((start + 1, end + 1))
+}
+
+const DEFINERS: [&str; 10] = [
+    "fn ",
+    "def ",
+    "function ",
+    "func ",
+    "class ",
+    "struct ",
+    "enum ",
+    "trait ",
+    "impl ",
+    "interface ",
+];
+
+fn defines(line: &str, name: &str) -> bool {
+    let Some(at) = find_word(line, name) else {
+        return false;
+    };
+    let before = line.get(..at).unwrap_or_default();
+    let after = line.get(at + name.len()..).unwrap_or_default().trim_start();
+    DEFINERS.iter().any(|d| before.trim_start().contains(d))
+        || (after.starts_with('(')
+            && !before.trim().is_empty()
+            && !before.contains('=')
+            && !line.trim_end().ends_with(';'))
+}
+
+/// Byte offset of `word` in `line` where it is not part of a longer identifier.
+fn find_word(line: &str, word: &str) -> Option<usize> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    line.match_indices(word).map(|(i, _)| i).find(|&i| {
+        let before = line.get(..i).and_then(|s| s.chars().next_back());
+        let after = line.get(i + word.len()..).and_then(|s| s.chars().next());
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+/// Zero-based last line of the block starting at `start`.
+fn block_end(lines: &[String], start: usize) -> usize {
+    let mut depth = 0usize;
+    let mut opened = false;
+    for (i, line) in lines.iter().enumerate().skip(start) {
+        for c in line.chars() {
+            match c {
+                '{' => {
+                    depth += 1;
+                    opened = true;
+                }
+                '}' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+        if opened && depth == 0 {
+            return i;
+        }
+        // A line ending in `:` before any brace opens an indented block instead.
+        if !opened && line.trim_end().ends_with(':') {
+            return indented_end(lines, start);
+        }
+        if !opened && line.trim_end().ends_with(';') {
+            return i;
+        }
+    }
+    lines.len().saturating_sub(1).max(start)
+}
+
+fn indented_end(lines: &[String], start: usize) -> usize {
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let base = lines.get(start).map_or(0, |l| indent(l));
+    let mut end = start;
+    for (i, line) in lines.iter().enumerate().skip(start + 1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if indent(line) <= base {
+            break;
+        }
+        end = i;
+    }
+    end
+}
+
+#[cfg(test)]
+mod tests;
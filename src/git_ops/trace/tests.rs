@@ -0,0 +1,59 @@
+use super::*;
+use crate::git_ops::testing::{self, commit};
+
+fn text(lines: &[&str]) -> String {
+    lines.join("\n") + "\n"
+}
+
+const OLD: [&str; 10] = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10"];
+
+#[test]
+fn map_line_shifts_past_inserted_lines() -> Result<()> {
+    let new = text(&["1", "2", "3", "a", "b", "4", "5", "6", "7", "8", "9", "10"]);
+    let hunks = hunks(text(&OLD).as_bytes(), new.as_bytes())?;
+    assert_eq!(map_start(&hunks, 1), 1);
+    assert_eq!(map_end(&hunks, 3), 3);
+    assert_eq!((map_start(&hunks, 6), map_end(&hunks, 12)), (4, 10));
+    // Lines the commit added map to an empty range, ending the trace.
+    assert_eq!((map_start(&hunks, 4), map_end(&hunks, 5)), (4, 3));
+    Ok(())
+}
+
+#[test]
+fn map_line_shifts_past_deleted_lines() -> Result<()> {
+    let new = text(&["1", "2", "3", "4", "7", "8", "9", "10"]);
+    let hunks = hunks(text(&OLD).as_bytes(), new.as_bytes())?;
+    assert_eq!(map_end(&hunks, 4), 4);
+    assert_eq!((map_start(&hunks, 5), map_end(&hunks, 8)), (7, 10));
+    // A range spanning the deletion grows to cover the deleted lines.
+    assert_eq!((map_start(&hunks, 3), map_end(&hunks, 5)), (3, 7));
+    Ok(())
+}
+
+#[test]
+fn map_line_covers_rewritten_lines() -> Result<()> {
+    let new = text(&["1", "2", "3", "4", "x", "y", "6", "7", "8", "9", "10"]);
+    let hunks = hunks(text(&OLD).as_bytes(), new.as_bytes())?;
+    assert_eq!((map_start(&hunks, 5), map_end(&hunks, 6)), (5, 5));
+    assert_eq!((map_start(&hunks, 6), map_end(&hunks, 7)), (5, 6));
+    assert_eq!(map_start(&hunks, 11), 10);
+    Ok(())
+}
+
+#[test]
+fn trace_lines_follows_a_range_back_to_its_creation() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    let created = commit(&repo, &[("f.txt", Some(&text(&OLD)))])?;
+    let shifted = ["a", "b", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10"];
+    let moved = commit(&repo, &[("f.txt", Some(&text(&shifted)))])?;
+    let edited = [
+        "a", "b", "1", "2", "3", "4", "5", "6", "7", "changed", "9", "10",
+    ];
+    let edited = commit(&repo, &[("f.txt", Some(&text(&edited)))])?;
+
+    let trace = trace_lines(&repo, &[edited, moved, created], 0, "f.txt", (9, 10))?;
+    let ranges: Vec<(usize, LineRange)> = trace.ranges.into_iter().collect();
+    assert_eq!(ranges, [(0, (9, 10)), (1, (9, 10)), (2, (7, 8))]);
+    assert_eq!(trace.commits, [0, 2]);
+    Ok(())
+}
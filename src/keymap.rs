@@ -0,0 +1,144 @@
+mod action;
+mod chord;
+
+use crate::views::ViewMode;
+
+pub use action::Action;
+pub use chord::KeyChord;
+
+/// Outcome of feeding the keys typed so far to the keymap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Lookup {
+    Action(Action),
+    /// The keys are the start of a longer sequence; wait for more.
+    Pending,
+    None,
+}
+
+/// Key sequences bound to actions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: Vec<(Vec<KeyChord>, Action)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let mut keymap = Self {
+            bindings: Vec::new(),
+        };
+        for (keys, action) in DEFAULT_BINDINGS {
+            let sequences = keys
+                .iter()
+                .filter_map(|k| KeyChord::parse_sequence(k))
+                .collect();
+            keymap.bind(*action, sequences);
+        }
+        keymap
+    }
+}
+
+const DEFAULT_BINDINGS: &[(&[&str], Action)] = &[
+    (&["q", "Q"], Action::Quit),
+    (&["r", "R"], Action::Restore),
+    (&["e", "E"], Action::OpenExternal),
+    (&["c", "C"], Action::ShowFile),
+    (&["u"], Action::Undo),
+    (&["esc"], Action::ClearSelection),
+    (&["left"], Action::NextCommit),
+    (&["right"], Action::PrevCommit),
+    (&["<"], Action::NextTag),
+    (&[">"], Action::PrevTag),
+    (&["up"], Action::ScrollUp),
+    (&["down"], Action::ScrollDown),
+    (&["h"], Action::FocusLeft),
+    (&["l"], Action::FocusRight),
+    (&["k"], Action::FocusUp),
+    (&["j"], Action::FocusDown),
+    (&["enter"], Action::Activate),
+    (&["v"], Action::ToggleSelect),
+    (&["V"], Action::SelectDir),
+    (&["i"], Action::EnterDir),
+    (&["backspace"], Action::LeaveDir),
+    (&["tab"], Action::NextView),
+    (&["shift-tab"], Action::PrevView),
+    (&["1"], Action::View(ViewMode::Terrain)),
+    (&["2"], Action::View(ViewMode::Seismic)),
+    (&["3"], Action::View(ViewMode::Strata)),
+    (&["4"], Action::View(ViewMode::Flow)),
+    (&["5"], Action::View(ViewMode::Constellation)),
+    (&["6"], Action::View(ViewMode::Surgery)),
+    (&["f", "F"], Action::ToggleFilter),
+    (&["+", "="], Action::ZoomIn),
+    (&["-"], Action::ZoomOut),
+    (&["a", "A"], Action::ToggleAggregate),
+    (&["s", "S"], Action::CycleSort),
+    (&["g", "G"], Action::ToggleGrouping),
+    (&["/", "ctrl-p"], Action::FindFile),
+    (&["d", "D"], Action::Graveyard),
+    (&["ctrl-f"], Action::SearchCommits),
+    (&["x", "X"], Action::Pickaxe),
+    (&["L"], Action::TraceLines),
+    (&["n"], Action::NextMatch),
+    (&["N"], Action::PrevMatch),
+    (&["b", "B"], Action::MarkBase),
+    (&["?"], Action::Help),
+    (&["p", "space"], Action::TogglePlayback),
+    (&["]"], Action::PlaybackFaster),
+    (&["["], Action::PlaybackSlower),
+    (&["m"], Action::SetMark),
+    (&["'"], Action::JumpToMark),
+    (&[":"], Action::CommandPalette),
+    (&["ctrl-b"], Action::CreateBranch),
+    (&["t"], Action::CreateTag),
+    (&["W"], Action::AddWorktree),
+];
+
+impl Keymap {
+    /// Replace the bindings of `action`, taking the given sequences away from any other action.
+    pub fn bind(&mut self, action: Action, sequences: Vec<Vec<KeyChord>>) {
+        self.bindings
+            .retain(|(keys, bound)| *bound != action && !sequences.contains(keys));
+        self.bindings
+            .extend(sequences.into_iter().map(|keys| (keys, action)));
+    }
+
+    /// Resolve the keys typed so far; an exact match wins over a longer sequence.
+    pub fn lookup(&self, keys: &[KeyChord]) -> Lookup {
+        if let Some((_, action)) = self.bindings.iter().find(|(k, _)| k == keys) {
+            return Lookup::Action(*action);
+        }
+        if self
+            .bindings
+            .iter()
+            .any(|(k, _)| k.len() > keys.len() && k.starts_with(keys))
+        {
+            return Lookup::Pending;
+        }
+        Lookup::None
+    }
+
+    /// Display strings of every sequence bound to `action`, in binding order.
+    pub fn keys_for(&self, action: Action) -> Vec<String> {
+        self.bindings
+            .iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(keys, _)| {
+                keys.iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect()
+    }
+
+    /// The first key bound to `action`, for compact hints.
+    pub fn key_for(&self, action: Action) -> Option<String> {
+        self.keys_for(action).into_iter().next()
+    }
+
+    /// Status-bar hint such as `[r]restore`, or nothing when the action is unbound.
+    pub fn hint(&self, action: Action) -> Option<String> {
+        self.key_for(action)
+            .map(|key| format!("[{key}]{}", action.label()))
+    }
+}
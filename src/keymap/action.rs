@@ -0,0 +1,379 @@
+use crate::views::ViewMode;
+
+/// Something the user can ask git-trek to do, independent of the key that triggers it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    Restore,
+    /// Open the selected file as of the current commit in `$EDITOR` or `$PAGER`.
+    OpenExternal,
+    /// Show the selected file's content at the current commit.
+    ShowFile,
+    /// Put back the working copy content replaced by the last restore.
+    Undo,
+    ClearSelection,
+    /// Step to the next commit in log order (older).
+    NextCommit,
+    /// Step to the previous commit in log order (newer).
+    PrevCommit,
+    /// Jump to the nearest older tagged commit.
+    NextTag,
+    /// Jump to the nearest newer tagged commit.
+    PrevTag,
+    ScrollUp,
+    ScrollDown,
+    FocusLeft,
+    FocusRight,
+    FocusUp,
+    FocusDown,
+    /// Select the focused element, as a click would.
+    Activate,
+    /// Add the focused file or directory to the restore selection, or remove it.
+    ToggleSelect,
+    /// Select the directory of the focused or selected file, widening on repeat.
+    SelectDir,
+    /// Narrow the path filter one directory down toward the selected file.
+    EnterDir,
+    /// Widen the path filter to its parent directory.
+    LeaveDir,
+    NextView,
+    PrevView,
+    View(ViewMode),
+    ToggleFilter,
+    ZoomIn,
+    ZoomOut,
+    ToggleAggregate,
+    CycleSort,
+    ToggleGrouping,
+    FindFile,
+    /// List files deleted within the loaded history, to restore their last version.
+    Graveyard,
+    SearchCommits,
+    Pickaxe,
+    /// Follow the lines given as the argument, or picked in the viewer, back through history.
+    TraceLines,
+    NextMatch,
+    PrevMatch,
+    /// Pin the current commit as the compare base, or unpin it.
+    MarkBase,
+    Help,
+    TogglePlayback,
+    PlaybackFaster,
+    PlaybackSlower,
+    /// Mark the current commit with the letter (and optional note) given as the argument.
+    SetMark,
+    /// Jump to the commit marked with the letter given as the argument.
+    JumpToMark,
+    CommandPalette,
+    /// Create a branch named by the argument at the current commit.
+    CreateBranch,
+    /// Tag the current commit; a message after the name makes an annotated tag.
+    CreateTag,
+    /// Add a linked worktree at the path given as the argument, on a new branch.
+    AddWorktree,
+    /// Jump to the commit named by the argument (sha, ref or revision expression).
+    Goto,
+    /// Switch to the view named by the argument.
+    SetView,
+    /// Reload history with the number of commits given as the argument.
+    SetLimit,
+    /// Only show files under the path prefix given as the argument; no argument clears it.
+    PathFilter,
+    /// Summarize the changes between the two revisions given as the argument.
+    Report,
+}
+
+impl Action {
+    pub const ALL: [Action; 58] = [
+        Action::Quit,
+        Action::Restore,
+        Action::OpenExternal,
+        Action::ShowFile,
+        Action::Undo,
+        Action::ClearSelection,
+        Action::NextCommit,
+        Action::PrevCommit,
+        Action::NextTag,
+        Action::PrevTag,
+        Action::ScrollUp,
+        Action::ScrollDown,
+        Action::FocusLeft,
+        Action::FocusRight,
+        Action::FocusUp,
+        Action::FocusDown,
+        Action::Activate,
+        Action::ToggleSelect,
+        Action::SelectDir,
+        Action::EnterDir,
+        Action::LeaveDir,
+        Action::NextView,
+        Action::PrevView,
+        Action::View(ViewMode::Terrain),
+        Action::View(ViewMode::Seismic),
+        Action::View(ViewMode::Strata),
+        Action::View(ViewMode::Flow),
+        Action::View(ViewMode::Constellation),
+        Action::View(ViewMode::Surgery),
+        Action::ToggleFilter,
+        Action::ZoomIn,
+        Action::ZoomOut,
+        Action::ToggleAggregate,
+        Action::CycleSort,
+        Action::ToggleGrouping,
+        Action::FindFile,
+        Action::Graveyard,
+        Action::SearchCommits,
+        Action::Pickaxe,
+        Action::TraceLines,
+        Action::NextMatch,
+        Action::PrevMatch,
+        Action::MarkBase,
+        Action::Help,
+        Action::TogglePlayback,
+        Action::PlaybackFaster,
+        Action::PlaybackSlower,
+        Action::SetMark,
+        Action::JumpToMark,
+        Action::CommandPalette,
+        Action::CreateBranch,
+        Action::CreateTag,
+        Action::AddWorktree,
+        Action::Goto,
+        Action::SetView,
+        Action::SetLimit,
+        Action::PathFilter,
+        Action::Report,
+    ];
+
+    /// Name used for this action in the `[keys]` config table.
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Restore => "restore",
+            Action::OpenExternal => "open",
+            Action::ShowFile => "show_file",
+            Action::Undo => "undo",
+            Action::ClearSelection => "clear_selection",
+            Action::NextCommit => "next_commit",
+            Action::PrevCommit => "prev_commit",
+            Action::NextTag => "next_tag",
+            Action::PrevTag => "prev_tag",
+            Action::ScrollUp => "scroll_up",
+            Action::ScrollDown => "scroll_down",
+            Action::FocusLeft => "focus_left",
+            Action::FocusRight => "focus_right",
+            Action::FocusUp => "focus_up",
+            Action::FocusDown => "focus_down",
+            Action::Activate => "activate",
+            Action::ToggleSelect => "toggle_select",
+            Action::SelectDir => "select_dir",
+            Action::EnterDir => "enter_dir",
+            Action::LeaveDir => "leave_dir",
+            Action::NextView => "next_view",
+            Action::PrevView => "prev_view",
+            Action::View(ViewMode::Terrain) => "view_terrain",
+            Action::View(ViewMode::Seismic) => "view_seismic",
+            Action::View(ViewMode::Strata) => "view_strata",
+            Action::View(ViewMode::Flow) => "view_flow",
+            Action::View(ViewMode::Constellation) => "view_constellation",
+            Action::View(ViewMode::Surgery) => "view_surgery",
+            Action::ToggleFilter => "toggle_filter",
+            Action::ZoomIn => "zoom_in",
+            Action::ZoomOut => "zoom_out",
+            Action::ToggleAggregate => "toggle_aggregate",
+            Action::CycleSort => "cycle_sort",
+            Action::ToggleGrouping => "toggle_grouping",
+            Action::FindFile => "find_file",
+            Action::Graveyard => "graveyard",
+            Action::SearchCommits => "search_commits",
+            Action::Pickaxe => "pickaxe",
+            Action::TraceLines => "trace",
+            Action::NextMatch => "next_match",
+            Action::PrevMatch => "prev_match",
+            Action::MarkBase => "mark_base",
+            Action::Help => "help",
+            Action::TogglePlayback => "toggle_playback",
+            Action::PlaybackFaster => "playback_faster",
+            Action::PlaybackSlower => "playback_slower",
+            Action::SetMark => "mark",
+            Action::JumpToMark => "jump",
+            Action::CommandPalette => "command_palette",
+            Action::CreateBranch => "branch",
+            Action::CreateTag => "tag",
+            Action::AddWorktree => "worktree",
+            Action::Goto => "goto",
+            Action::SetView => "view",
+            Action::SetLimit => "limit",
+            Action::PathFilter => "filter",
+            Action::Report => "report",
+        }
+    }
+
+    /// Short word shown next to the bound key in status bars.
+    pub fn label(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Restore => "restore",
+            Action::OpenExternal => "open",
+            Action::ShowFile => "show",
+            Action::Undo => "undo",
+            Action::ClearSelection => "clear",
+            Action::NextCommit => "older",
+            Action::PrevCommit => "newer",
+            Action::NextTag => "older tag",
+            Action::PrevTag => "newer tag",
+            Action::ScrollUp => "up",
+            Action::ScrollDown => "down",
+            Action::FocusLeft | Action::FocusRight | Action::FocusUp | Action::FocusDown => "focus",
+            Action::Activate => "select",
+            Action::ToggleSelect => "multi-select",
+            Action::SelectDir => "select dir",
+            Action::EnterDir => "into dir",
+            Action::LeaveDir => "up dir",
+            Action::NextView => "next view",
+            Action::PrevView => "prev view",
+            Action::View(mode) => mode.name(),
+            Action::ToggleFilter => "filter",
+            Action::ZoomIn => "zoom in",
+            Action::ZoomOut => "zoom out",
+            Action::ToggleAggregate => "aggregate",
+            Action::CycleSort => "sort",
+            Action::ToggleGrouping => "group",
+            Action::FindFile => "find",
+            Action::Graveyard => "deleted",
+            Action::SearchCommits => "search",
+            Action::Pickaxe => "pickaxe",
+            Action::TraceLines => "trace lines",
+            Action::NextMatch => "next match",
+            Action::PrevMatch => "prev match",
+            Action::MarkBase => "base",
+            Action::Help => "help",
+            Action::TogglePlayback => "play",
+            Action::PlaybackFaster => "faster",
+            Action::PlaybackSlower => "slower",
+            Action::SetMark => "mark",
+            Action::JumpToMark => "jump to mark",
+            Action::CommandPalette => "commands",
+            Action::CreateBranch => "branch",
+            Action::CreateTag => "tag",
+            Action::AddWorktree => "worktree",
+            Action::Goto => "goto",
+            Action::SetView => "view",
+            Action::SetLimit => "limit",
+            Action::PathFilter => "path filter",
+            Action::Report => "report",
+        }
+    }
+
+    /// One-line explanation shown in the command palette.
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::Quit => "Quit git-trek",
+            Action::Restore => "Restore the selected file from the current commit",
+            Action::OpenExternal => "Open the selected file as of this commit in $EDITOR or $PAGER",
+            Action::ShowFile => "Show the selected file as of this commit, with its changes marked",
+            Action::Undo => "Undo the last restore",
+            Action::ClearSelection => "Clear the selected file and any search",
+            Action::NextCommit => "Step to the next older commit",
+            Action::PrevCommit => "Step to the next newer commit",
+            Action::NextTag => "Jump to the nearest older tag",
+            Action::PrevTag => "Jump to the nearest newer tag",
+            Action::ScrollUp => "Scroll the Seismic file list up",
+            Action::ScrollDown => "Scroll the Seismic file list down",
+            Action::FocusLeft => "Move the keyboard focus left",
+            Action::FocusRight => "Move the keyboard focus right",
+            Action::FocusUp => "Move the keyboard focus up",
+            Action::FocusDown => "Move the keyboard focus down",
+            Action::Activate => "Select the focused file, cell or directory",
+            Action::ToggleSelect => {
+                "Add the focused file or directory to the selection, or remove it"
+            }
+            Action::SelectDir => "Select the directory of the selected file; repeat to widen",
+            Action::EnterDir => "Show only the next directory down toward the selected file",
+            Action::LeaveDir => "Show the parent of the directory shown",
+            Action::NextView => "Switch to the next view",
+            Action::PrevView => "Switch to the previous view",
+            Action::View(ViewMode::Terrain) => "Show the Terrain treemap",
+            Action::View(ViewMode::Seismic) => "Show the Seismic activity grid",
+            Action::View(ViewMode::Strata) => "Show the Strata view",
+            Action::View(ViewMode::Flow) => "Show the Flow view",
+            Action::View(ViewMode::Constellation) => "Show the Constellation view",
+            Action::View(ViewMode::Surgery) => "Show the Surgery view",
+            Action::ToggleFilter => "Hide files with no activity in the visible range",
+            Action::ZoomIn => "Zoom the Seismic time axis in",
+            Action::ZoomOut => "Zoom the Seismic time axis out",
+            Action::ToggleAggregate => "Switch Seismic buckets between max and sum",
+            Action::CycleSort => "Cycle the Seismic row order",
+            Action::ToggleGrouping => "Group Seismic rows by directory",
+            Action::FindFile => "Fuzzy-find a file and reveal it",
+            Action::Graveyard => "List deleted files and restore the last version of one",
+            Action::SearchCommits => "Search commit messages, authors, refs and shas",
+            Action::Pickaxe => "Find commits that add or remove a string",
+            Action::TraceLines => {
+                "trace <start>,<end> | :<function>: commits that changed those lines"
+            }
+            Action::NextMatch => "Jump to the next search match",
+            Action::PrevMatch => "Jump to the previous search match",
+            Action::MarkBase => "Compare against the current commit, or stop comparing",
+            Action::Help => "Show the keys available in this view",
+            Action::TogglePlayback => "Play history from oldest to newest, or pause",
+            Action::PlaybackFaster => "Speed up playback",
+            Action::PlaybackSlower => "Slow down playback",
+            Action::SetMark => "mark <letter> [note]: bookmark the current commit",
+            Action::JumpToMark => "jump <letter>: go to a bookmarked commit",
+            Action::CommandPalette => "Open this command palette",
+            Action::CreateBranch => "branch <name>: create a branch at the current commit",
+            Action::CreateTag => {
+                "tag <name> [message]: tag the current commit, annotated with a message"
+            }
+            Action::AddWorktree => {
+                "worktree <path> [branch]: check the current commit out in a new worktree beside the repository"
+            }
+            Action::Goto => "goto <rev>: jump to a commit by sha or ref",
+            Action::SetView => "view <name>: switch to a view by name",
+            Action::SetLimit => "limit <n>: reload history with n commits",
+            Action::PathFilter => "filter [prefix]: only show files under a path",
+            Action::Report => {
+                "report [<from>..<to>]: summarize a release, from the last tag by default"
+            }
+        }
+    }
+
+    /// Whether the action does anything in `view`; Seismic controls are listed only there.
+    pub fn available_in(self, view: ViewMode) -> bool {
+        let seismic_only = matches!(
+            self,
+            Action::ScrollUp
+                | Action::ScrollDown
+                | Action::ToggleFilter
+                | Action::ZoomIn
+                | Action::ZoomOut
+                | Action::ToggleAggregate
+                | Action::CycleSort
+                | Action::ToggleGrouping
+        );
+        !seismic_only || view == ViewMode::Seismic
+    }
+
+    /// Whether the action needs an argument typed after its name in the palette.
+    pub fn takes_argument(self) -> bool {
+        matches!(
+            self,
+            Action::Goto
+                | Action::CreateBranch
+                | Action::CreateTag
+                | Action::AddWorktree
+                | Action::TraceLines
+                | Action::SetView
+                | Action::SetLimit
+                | Action::PathFilter
+                | Action::Report
+                | Action::SetMark
+                | Action::JumpToMark
+        )
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|a| a.name() == name)
+    }
+}
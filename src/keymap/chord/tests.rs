@@ -0,0 +1,71 @@
+use super::*;
+
+fn parsed(text: &str) -> Option<(KeyCode, KeyModifiers)> {
+    KeyChord::parse(text).map(|c| (c.code, c.modifiers))
+}
+
+#[test]
+fn parse_plain_keys() {
+    assert_eq!(parsed("q"), Some((KeyCode::Char('q'), KeyModifiers::NONE)));
+    assert_eq!(parsed("N"), Some((KeyCode::Char('N'), KeyModifiers::NONE)));
+    assert_eq!(parsed("-"), Some((KeyCode::Char('-'), KeyModifiers::NONE)));
+    assert_eq!(parsed("Left"), Some((KeyCode::Left, KeyModifiers::NONE)));
+    assert_eq!(
+        parsed("space"),
+        Some((KeyCode::Char(' '), KeyModifiers::NONE))
+    );
+    assert_eq!(parsed("f5"), Some((KeyCode::F(5), KeyModifiers::NONE)));
+}
+
+#[test]
+fn parse_modifiers_in_any_case_and_order() {
+    let ctrl_alt = KeyModifiers::CONTROL | KeyModifiers::ALT;
+    assert_eq!(
+        parsed("ctrl-p"),
+        Some((KeyCode::Char('p'), KeyModifiers::CONTROL))
+    );
+    assert_eq!(parsed("Ctrl-Alt-x"), Some((KeyCode::Char('x'), ctrl_alt)));
+    assert_eq!(parsed("alt-ctrl-x"), Some((KeyCode::Char('x'), ctrl_alt)));
+    // A lone `-` after a modifier is the minus key, not another modifier.
+    assert_eq!(
+        parsed("ctrl--"),
+        Some((KeyCode::Char('-'), KeyModifiers::CONTROL))
+    );
+}
+
+#[test]
+fn parse_folds_shift_into_the_key() {
+    assert_eq!(
+        parsed("shift-tab"),
+        Some((KeyCode::BackTab, KeyModifiers::NONE))
+    );
+    assert_eq!(
+        parsed("shift-a"),
+        Some((KeyCode::Char('A'), KeyModifiers::NONE))
+    );
+    assert_eq!(
+        parsed("ctrl-shift-a"),
+        Some((KeyCode::Char('A'), KeyModifiers::CONTROL))
+    );
+    assert_eq!(
+        parsed("shift-left"),
+        Some((KeyCode::Left, KeyModifiers::SHIFT))
+    );
+}
+
+#[test]
+fn parse_rejects_unknown_keys() {
+    assert_eq!(parsed(""), None);
+    assert_eq!(parsed("ctrl-"), None);
+    assert_eq!(parsed("f13"), None);
+    assert_eq!(parsed("hyper-x"), None);
+    assert_eq!(parsed("escape"), None);
+}
+
+#[test]
+fn parse_sequence_splits_on_whitespace() {
+    let g = KeyChord::new(KeyCode::Char('g'), KeyModifiers::NONE);
+    assert_eq!(KeyChord::parse_sequence("g g"), Some(vec![g, g]));
+    assert_eq!(KeyChord::parse_sequence("  "), None);
+    assert_eq!(KeyChord::parse_sequence("g bogus"), None);
+}
@@ -1,7 +1,12 @@
 pub mod app;
 pub mod cli;
+pub mod config;
 pub mod data;
 pub mod error;
+pub mod fuzzy;
 pub mod git_ops;
+pub mod keymap;
+pub mod marks;
 pub mod mouse;
+pub mod report;
 pub mod views;
@@ -1,14 +1,21 @@
 mod app;
 mod cli;
+mod config;
 mod data;
 mod error;
+mod fuzzy;
 mod git_ops;
+mod keymap;
+mod marks;
 mod mouse;
+mod report;
 mod views;
 
 mod event;
 mod terminal;
 
+use std::io::{self, Write};
+
 use anyhow::Result;
 
 use crate::app::App;
@@ -23,16 +30,30 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
-    let mut terminal = terminal::setup()?;
+    if let Some(range) = &cli.report {
+        let app = App::new(&cli)?;
+        print!("{}", app.release_report(range)?.to_markdown());
+        return Ok(());
+    }
+
+    // Load before taking over the terminal, so config and marks errors stay readable.
     let mut app = App::new(&cli)?;
+    let mut terminal = terminal::setup()?;
 
     let result = event::run(&mut terminal, &mut app);
 
     terminal::restore(&mut terminal)?;
 
+    // Restored content owns stdout, so the summary goes to stderr then.
+    let output = app.stdout_output();
     if let Some(msg) = app.message() {
-        println!("{msg}");
+        if output.is_empty() {
+            println!("{msg}");
+        } else {
+            eprintln!("{msg}");
+        }
     }
+    io::stdout().write_all(output)?;
 
     result
 }
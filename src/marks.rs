@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use git2::Oid;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, TrekError};
+
+/// A bookmarked commit with an optional note.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mark {
+    pub oid: Oid,
+    pub note: Option<String>,
+}
+
+/// Bookmarks of one repository, stored in `.git/git-trek/marks.toml`.
+#[derive(Clone, Debug, Default)]
+pub struct Marks {
+    marks: BTreeMap<char, Mark>,
+    path: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MarkEntry {
+    oid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+impl Marks {
+    /// Load the marks saved in `git_dir`, or start empty if there are none yet.
+    pub fn load(git_dir: &Path) -> Result<Self> {
+        let path = git_dir.join("git-trek").join("marks.toml");
+        let mut marks = BTreeMap::new();
+
+        if path.is_file() {
+            let text = std::fs::read_to_string(&path)?;
+            let entries: BTreeMap<String, MarkEntry> = toml::from_str(&text)
+                .map_err(|e| TrekError::Marks(format!("{}: {}", path.display(), e.message())))?;
+            for (key, entry) in entries {
+                let mut chars = key.chars();
+                let (Some(letter), None) = (chars.next(), chars.next()) else {
+                    return Err(TrekError::Marks(format!("invalid mark name '{key}'")));
+                };
+                let oid = Oid::from_str(&entry.oid)?;
+                marks.insert(
+                    letter,
+                    Mark {
+                        oid,
+                        note: entry.note,
+                    },
+                );
+            }
+        }
+
+        Ok(Self { marks, path })
+    }
+
+    fn save(&self) -> Result<()> {
+        let entries: BTreeMap<String, MarkEntry> = self
+            .marks
+            .iter()
+            .map(|(letter, mark)| {
+                let entry = MarkEntry {
+                    oid: mark.oid.to_string(),
+                    note: mark.note.clone(),
+                };
+                (letter.to_string(), entry)
+            })
+            .collect();
+        let text = toml::to_string(&entries).map_err(|e| TrekError::Marks(e.to_string()))?;
+
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&self.path, text)?;
+        Ok(())
+    }
+
+    /// Set or replace a mark and write the marks back to disk.
+    pub fn set(&mut self, letter: char, mark: Mark) -> Result<()> {
+        self.marks.insert(letter, mark);
+        self.save()
+    }
+
+    pub fn get(&self, letter: char) -> Option<&Mark> {
+        self.marks.get(&letter)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (char, &Mark)> {
+        self.marks.iter().map(|(letter, mark)| (*letter, mark))
+    }
+}
@@ -8,6 +8,11 @@ pub enum HitTarget {
     File(String),
     ViewTab(usize),
     SeismicCell(usize),
+    SeismicBucket(usize),
+    SeismicDir(String),
+    FinderItem(usize),
+    PaletteItem(usize),
+    GraveyardItem(usize),
 }
 
 /// A rectangular region that can be clicked.
@@ -0,0 +1,312 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use git2::Repository;
+
+use crate::config::HealthThresholds;
+use crate::data::{Health, History};
+use crate::error::{Result, TrekError};
+use crate::git_ops::{self, TreeChanges};
+
+/// Rows kept in each ranked section.
+const TOP: usize = 10;
+
+/// A file whose line count changed across the range.
+#[derive(Clone, Debug)]
+pub struct SizeChange {
+    pub path: String,
+    pub old: usize,
+    pub new: usize,
+    pub health: Health,
+}
+
+impl SizeChange {
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn delta(&self) -> i64 {
+        self.new as i64 - self.old as i64
+    }
+}
+
+/// A commit that cut a file below the trauma threshold.
+#[derive(Clone, Debug)]
+pub struct TraumaEvent {
+    pub short_oid: String,
+    pub summary: String,
+    pub path: String,
+    pub old: usize,
+    pub new: usize,
+}
+
+/// A file ranked by how much it churned across the range.
+#[derive(Clone, Debug)]
+pub struct Touched {
+    pub path: String,
+    /// Commits that changed its line count.
+    pub commits: usize,
+    pub score: u64,
+}
+
+/// Everything that happened between two releases.
+#[derive(Clone, Debug)]
+pub struct ReleaseReport {
+    /// Names the range was given by, such as `v1.0` and `v1.1`.
+    pub from: String,
+    pub to: String,
+    /// Commits after `from` up to and including `to`.
+    pub commits: usize,
+    pub changes: TreeChanges,
+    pub growers: Vec<SizeChange>,
+    pub shrinkers: Vec<SizeChange>,
+    pub trauma: Vec<TraumaEvent>,
+    pub most_touched: Vec<Touched>,
+    /// Authors with their commit counts, most active first.
+    pub contributors: Vec<(String, usize)>,
+}
+
+impl ReleaseReport {
+    /// Summarize what landed after the commit at index `from` up to the one at `to`;
+    /// indices run newest first, so `to <= from`.
+    pub fn build(
+        repo: &Repository,
+        history: &History,
+        (from, to): (usize, usize),
+        (from_name, to_name): (String, String),
+        thresholds: &HealthThresholds,
+    ) -> Result<Self> {
+        let (Some(old), Some(new)) = (history.commits.get(from), history.commits.get(to)) else {
+            return Err(TrekError::Report(format!(
+                "{from_name}..{to_name} is outside the loaded history"
+            )));
+        };
+        let changes = git_ops::tree_changes(repo, old.oid, new.oid)?;
+
+        let mut sizes: Vec<SizeChange> = history
+            .files
+            .iter()
+            .filter_map(|(path, file)| {
+                let (old, new) = (file.lines_at(from)?, file.lines_at(to)?);
+                (old != new).then(|| SizeChange {
+                    path: path.clone(),
+                    old,
+                    new,
+                    health: Health::from_change(Some(old), Some(new), thresholds),
+                })
+            })
+            .collect();
+        sizes.sort_by(|a, b| b.delta().cmp(&a.delta()).then(a.path.cmp(&b.path)));
+        let growers = sizes.iter().filter(|s| s.delta() > 0).take(TOP).cloned();
+        let shrinkers = sizes
+            .iter()
+            .rev()
+            .filter(|s| s.delta() < 0)
+            .take(TOP)
+            .cloned();
+
+        let mut trauma = Vec::new();
+        for (i, commit) in history.commits.iter().enumerate().take(from).skip(to) {
+            let mut paths: Vec<(&String, usize, usize)> = history
+                .files
+                .iter()
+                .filter(|(_, file)| file.health_at(i, Some(i + 1), thresholds) == Health::Trauma)
+                .filter_map(|(path, file)| Some((path, file.lines_at(i + 1)?, file.lines_at(i)?)))
+                .collect();
+            paths.sort();
+            trauma.extend(paths.into_iter().map(|(path, old, new)| TraumaEvent {
+                short_oid: commit.short_oid.clone(),
+                summary: commit.summary.clone(),
+                path: path.clone(),
+                old,
+                new,
+            }));
+        }
+
+        let mut most_touched: Vec<Touched> = history
+            .files
+            .iter()
+            .map(|(path, file)| Touched {
+                path: path.clone(),
+                commits: (to..from)
+                    .filter(|&i| file.lines_at(i) != file.lines_at(i + 1))
+                    .count(),
+                // Activity at index i compares with i - 1, so shifting by one scores
+                // each commit in the range against its parent.
+                score: file.activity_score(to + 1, from + 1),
+            })
+            .filter(|t| t.commits > 0)
+            .collect();
+        most_touched.sort_by(|a, b| {
+            (b.score, b.commits)
+                .cmp(&(a.score, a.commits))
+                .then(a.path.cmp(&b.path))
+        });
+        most_touched.truncate(TOP);
+
+        let mut authors: HashMap<&str, usize> = HashMap::new();
+        for commit in history.commits.get(to..from).unwrap_or_default() {
+            *authors.entry(commit.author.as_str()).or_default() += 1;
+        }
+        let mut contributors: Vec<(String, usize)> = authors
+            .into_iter()
+            .map(|(name, n)| (name.to_string(), n))
+            .collect();
+        contributors.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        Ok(Self {
+            from: from_name,
+            to: to_name,
+            commits: from - to,
+            changes,
+            growers: growers.collect(),
+            shrinkers: shrinkers.collect(),
+            trauma,
+            most_touched,
+            contributors,
+        })
+    }
+
+    /// The report as a Markdown document, with tables padded to line up in plain text.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# Release report: {}..{}\n\n", self.from, self.to);
+        let _ = writeln!(
+            out,
+            "{} by {}.",
+            plural(self.commits, "commit"),
+            plural(self.contributors.len(), "contributor")
+        );
+
+        let files = |paths: &[String]| paths.iter().map(|p| format!("`{p}`")).collect();
+        list(&mut out, "Files added", files(&self.changes.added));
+        list(&mut out, "Files deleted", files(&self.changes.deleted));
+        list(
+            &mut out,
+            "Files renamed",
+            self.changes
+                .renamed
+                .iter()
+                .map(|(old, new)| format!("`{old}` → `{new}`"))
+                .collect(),
+        );
+
+        let sizes = |changes: &[SizeChange]| {
+            changes
+                .iter()
+                .map(|s| {
+                    vec![
+                        format!("`{}`", s.path),
+                        s.old.to_string(),
+                        s.new.to_string(),
+                        format!("{:+}", s.delta()),
+                        s.health.name().to_string(),
+                    ]
+                })
+                .collect()
+        };
+        let size_header = ["File", "Before", "After", "Change", "Health"];
+        table(
+            &mut out,
+            "Biggest growers",
+            &size_header,
+            sizes(&self.growers),
+        );
+        table(
+            &mut out,
+            "Biggest shrinkers",
+            &size_header,
+            sizes(&self.shrinkers),
+        );
+        table(
+            &mut out,
+            "Trauma events",
+            &["Commit", "File", "Before", "After", "Summary"],
+            self.trauma
+                .iter()
+                .map(|t| {
+                    vec![
+                        format!("`{}`", t.short_oid),
+                        format!("`{}`", t.path),
+                        t.old.to_string(),
+                        t.new.to_string(),
+                        t.summary.replace('|', "\\|"),
+                    ]
+                })
+                .collect(),
+        );
+        table(
+            &mut out,
+            "Most touched files",
+            &["File", "Commits", "Activity"],
+            self.most_touched
+                .iter()
+                .map(|t| {
+                    vec![
+                        format!("`{}`", t.path),
+                        t.commits.to_string(),
+                        t.score.to_string(),
+                    ]
+                })
+                .collect(),
+        );
+        table(
+            &mut out,
+            "Contributors",
+            &["Author", "Commits"],
+            self.contributors
+                .iter()
+                .map(|(name, n)| vec![name.clone(), n.to_string()])
+                .collect(),
+        );
+        out
+    }
+}
+
+fn plural(n: usize, word: &str) -> String {
+    if n == 1 {
+        format!("{n} {word}")
+    } else {
+        format!("{n} {word}s")
+    }
+}
+
+fn list(out: &mut String, title: &str, items: Vec<String>) {
+    let _ = writeln!(out, "\n## {title} ({})\n", items.len());
+    if items.is_empty() {
+        out.push_str("_None._\n");
+    }
+    for item in items {
+        let _ = writeln!(out, "- {item}");
+    }
+}
+
+fn table(out: &mut String, title: &str, header: &[&str], rows: Vec<Vec<String>>) {
+    let _ = writeln!(out, "\n## {title}\n");
+    if rows.is_empty() {
+        out.push_str("_None._\n");
+        return;
+    }
+    let widths: Vec<usize> = header
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            rows.iter()
+                .filter_map(|row| row.get(i))
+                .map(|cell| cell.chars().count())
+                .fold(h.chars().count(), usize::max)
+        })
+        .collect();
+    let line = |cells: Vec<String>| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect();
+        format!("| {} |\n", padded.join(" | "))
+    };
+    out.push_str(&line(header.iter().map(ToString::to_string).collect()));
+    out.push_str(&line(widths.iter().map(|&w| "-".repeat(w)).collect()));
+    for row in rows {
+        out.push_str(&line(row));
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,121 @@
+use super::*;
+use crate::git_ops::testing::{self, commit_as};
+
+fn lines(n: usize) -> String {
+    "line\n".repeat(n)
+}
+
+fn tag(repo: &Repository, name: &str, oid: git2::Oid) -> Result<()> {
+    repo.tag_lightweight(name, &repo.find_object(oid, None)?, false)?;
+    Ok(())
+}
+
+/// Oldest first: Dee lays out v1.0, Bob and Ann land v1.1, then Cy commits
+/// after the release. Indices run newest first, so v1.0 is 4 and v1.1 is 1.
+fn release(repo: &Repository) -> Result<()> {
+    let (big, small) = (lines(100), lines(10));
+    // Unlike contents, so the deletion and addition are not paired as a rename.
+    let (gone, new) = ("gone\n".repeat(5), "new\n".repeat(3));
+    let v1 = commit_as(
+        repo,
+        "Dee",
+        &[
+            ("big.rs", Some(&big)),
+            ("small.rs", Some(&small)),
+            ("gone.rs", Some(&gone)),
+        ],
+    )?;
+    tag(repo, "v1.0", v1)?;
+    commit_as(repo, "Bob", &[("big.rs", Some(&lines(20)))])?;
+    commit_as(repo, "Ann", &[("small.rs", Some(&lines(30)))])?;
+    let v2 = commit_as(
+        repo,
+        "Ann",
+        &[
+            ("big.rs", Some(&lines(10))),
+            ("small.rs", Some(&lines(40))),
+            ("new.rs", Some(&new)),
+            ("gone.rs", None),
+        ],
+    )?;
+    tag(repo, "v1.1", v2)?;
+    commit_as(repo, "Cy", &[("small.rs", Some(&lines(5)))])?;
+    Ok(())
+}
+
+#[test]
+fn build_covers_the_commits_after_from_up_to_to() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    release(&repo)?;
+    let history = git_ops::load_history(&repo, 10)?;
+    let names = ("v1.0".to_string(), "v1.1".to_string());
+    let report =
+        ReleaseReport::build(&repo, &history, (4, 1), names, &HealthThresholds::default())?;
+
+    assert_eq!(report.commits, 3);
+    assert_eq!(report.changes.added, ["new.rs"]);
+    assert_eq!(report.changes.deleted, ["gone.rs"]);
+
+    let sizes = |changes: &[SizeChange]| -> Vec<(String, usize, usize)> {
+        changes
+            .iter()
+            .map(|s| (s.path.clone(), s.old, s.new))
+            .collect()
+    };
+    assert_eq!(sizes(&report.growers), [("small.rs".to_string(), 10, 40)]);
+    assert_eq!(sizes(&report.shrinkers), [("big.rs".to_string(), 100, 10)]);
+
+    // Both cuts to big.rs are in the range, newest first; Cy's cut to small.rs is not.
+    let trauma: Vec<(usize, usize)> = report.trauma.iter().map(|t| (t.old, t.new)).collect();
+    assert_eq!(trauma, [(20, 10), (100, 20)]);
+    assert!(report.trauma.iter().all(|t| t.path == "big.rs"));
+
+    let touched: Vec<(&str, usize, u64)> = report
+        .most_touched
+        .iter()
+        .map(|t| (t.path.as_str(), t.commits, t.score))
+        .collect();
+    assert_eq!(
+        touched,
+        [
+            ("big.rs", 2, 90),
+            ("small.rs", 2, 30),
+            ("gone.rs", 1, 10),
+            ("new.rs", 1, 10)
+        ]
+    );
+
+    assert_eq!(
+        report.contributors,
+        [("Ann".to_string(), 2), ("Bob".to_string(), 1)]
+    );
+    Ok(())
+}
+
+#[test]
+fn markdown_summarizes_the_range() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    release(&repo)?;
+    let history = git_ops::load_history(&repo, 10)?;
+    let names = ("v1.0".to_string(), "v1.1".to_string());
+    let markdown =
+        ReleaseReport::build(&repo, &history, (4, 1), names, &HealthThresholds::default())?
+            .to_markdown();
+
+    assert!(markdown.starts_with("# Release report: v1.0..v1.1\n"));
+    assert!(markdown.contains("3 commits by 2 contributors."));
+    assert!(markdown.contains("## Files added (1)\n\n- `new.rs`\n"));
+    assert!(markdown.contains("## Files renamed (0)\n\n_None._\n"));
+    Ok(())
+}
+
+#[test]
+fn build_rejects_a_range_outside_the_loaded_history() -> Result<()> {
+    let (_dir, repo) = testing::init()?;
+    release(&repo)?;
+    let history = git_ops::load_history(&repo, 10)?;
+    let names = ("a".to_string(), "b".to_string());
+    let built = ReleaseReport::build(&repo, &history, (9, 1), names, &HealthThresholds::default());
+    assert!(matches!(built, Err(TrekError::Report(_))));
+    Ok(())
+}
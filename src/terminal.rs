@@ -1,4 +1,4 @@
-use std::io;
+use std::io::{self, IsTerminal, Write};
 
 use anyhow::Result;
 use crossterm::{
@@ -8,14 +8,36 @@ use crossterm::{
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 
-pub fn setup() -> Result<Terminal<CrosstermBackend<io::Stdout>>> {
+/// The terminal the UI draws on.
+pub type Tui = Terminal<CrosstermBackend<Box<dyn Write>>>;
+
+/// Take over the terminal; with stdout redirected, draw on stderr so stdout stays clean.
+pub fn setup() -> Result<Tui> {
+    enable_raw_mode()?;
+    let mut out: Box<dyn Write> = if io::stdout().is_terminal() {
+        Box::new(io::stdout())
+    } else {
+        Box::new(io::stderr())
+    };
+    execute!(out, EnterAlternateScreen, EnableMouseCapture)?;
+    Ok(Terminal::new(CrosstermBackend::new(out))?)
+}
+
+/// Hand the terminal to `f`, such as an editor run in the foreground, then take it back.
+pub fn suspend<T>(terminal: &mut Tui, f: impl FnOnce() -> T) -> Result<T> {
+    restore(terminal)?;
+    let result = f();
     enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    Ok(Terminal::new(CrosstermBackend::new(stdout))?)
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )?;
+    terminal.clear()?;
+    Ok(result)
 }
 
-pub fn restore(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
+pub fn restore(terminal: &mut Tui) -> Result<()> {
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -0,0 +1,71 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+
+use crate::data::{Commit, RefKind, RefLabel};
+
+/// Longest ref label written along the timeline.
+const MAX_LABEL: usize = 24;
+
+/// Colors as `git log --decorate` draws them.
+pub fn ref_style(kind: RefKind) -> Style {
+    match kind {
+        RefKind::Head => Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+        RefKind::Branch => Style::default().fg(Color::Green),
+        RefKind::Remote => Style::default().fg(Color::Red),
+        RefKind::Tag => Style::default().fg(Color::Yellow),
+    }
+}
+
+/// Write the names of the `shown` refs of `decorated` commits, each given with the
+/// column its label starts at, wherever the line is still bare. Labels never cover
+/// markers or each other.
+pub fn label_refs(
+    line: &mut [Span<'static>],
+    decorated: &[(usize, &Commit)],
+    bare: &str,
+    shown: impl Fn(&RefLabel) -> bool,
+) {
+    let mut free_from = 0;
+    for &(start, commit) in decorated {
+        // HEAD goes without saying next to the branch it is on.
+        let labels: Vec<&RefLabel> = commit
+            .refs
+            .iter()
+            .filter(|r| shown(r) && (r.kind != RefKind::Head || commit.refs.len() == 1))
+            .collect();
+        let Some(first) = labels.first() else {
+            continue;
+        };
+        let names = labels
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+        let label: Vec<char> = names.chars().take(MAX_LABEL).collect();
+        let end = start + label.len();
+        let fits = start >= free_from
+            && line
+                .get(start..end)
+                .is_some_and(|cells| cells.iter().all(|c| c.content == bare));
+        if !fits {
+            continue;
+        }
+        let style = ref_style(first.kind);
+        for (cell, c) in line.iter_mut().skip(start).zip(label) {
+            *cell = Span::styled(c.to_string(), style);
+        }
+        // Keep one bare cell between neighbouring labels.
+        free_from = end + 1;
+    }
+}
+
+/// The tick drawn at a decorated commit's column, colored by its first ref.
+pub fn ref_tick(commit: &Commit) -> Option<Span<'static>> {
+    let first = commit.refs.first()?;
+    let glyph = if commit.is_tagged() { "◇" } else { "●" };
+    Some(Span::styled(glyph, ref_style(first.kind)))
+}
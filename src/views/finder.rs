@@ -0,0 +1,112 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::Finder;
+use crate::fuzzy::Match;
+use crate::mouse::{HitBox, HitTarget};
+use crate::views::Render;
+
+/// Draw the fuzzy file finder as a centered popup.
+pub fn draw(f: &mut Frame, finder: &Finder, render: &mut Render) {
+    let area = popup_area(f.area());
+    f.render_widget(Clear, area);
+
+    let title = format!(" FIND FILE │ {} matches ", finder.matches.len());
+    let block = Block::default().borders(Borders::ALL).title(title);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(inner);
+    let input_area = chunks.first().copied().unwrap_or_default();
+    let list_area = chunks.get(1).copied().unwrap_or_default();
+
+    let input = Line::from(vec![
+        Span::styled("> ", Style::default().fg(Color::Cyan)),
+        Span::raw(finder.query.as_str()),
+        Span::styled("▏", Style::default().fg(Color::Cyan)),
+    ]);
+    f.render_widget(Paragraph::new(input), input_area);
+
+    let rows = list_rows(
+        f,
+        list_area,
+        (&finder.matches, finder.selected),
+        |m, is_selected| Some(highlight(m, is_selected)),
+        HitTarget::FinderItem,
+    );
+    render.hit_boxes.extend(rows);
+}
+
+pub(super) fn highlight(m: &Match, is_selected: bool) -> Line<'static> {
+    let base = if is_selected {
+        Style::default().fg(Color::Black).bg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+    let hit = base.add_modifier(Modifier::BOLD).fg(if is_selected {
+        Color::Black
+    } else {
+        Color::Cyan
+    });
+
+    let spans: Vec<Span> = m
+        .text
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let style = if m.positions.contains(&i) { hit } else { base };
+            Span::styled(c.to_string(), style)
+        })
+        .collect();
+    Line::from(spans).style(base)
+}
+
+pub(super) fn popup_area(area: Rect) -> Rect {
+    let width = (area.width / 5 * 3).max(30).min(area.width);
+    let height = (area.height / 5 * 3).max(8).min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Draw the rows of a popup list that fit in `area`, scrolled to keep `selected` in
+/// view, and return a click target for each. `line` builds an item's row given
+/// whether it is selected; rows it gives `None` for are left blank.
+pub(super) fn list_rows<'a, T>(
+    f: &mut Frame,
+    area: Rect,
+    (items, selected): (&[T], usize),
+    line: impl Fn(&T, bool) -> Option<Line<'a>>,
+    target: impl Fn(usize) -> HitTarget,
+) -> Vec<HitBox> {
+    let visible = area.height as usize;
+    let offset = (selected + 1).saturating_sub(visible);
+    let mut hit_boxes = Vec::new();
+    for (row, (i, item)) in items
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .enumerate()
+    {
+        let Some(line) = line(item, i == selected) else {
+            continue;
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let rect = Rect::new(area.x, area.y + row as u16, area.width, 1);
+        f.render_widget(Paragraph::new(line), rect);
+        hit_boxes.push(HitBox::new(rect, target(i)));
+    }
+    hit_boxes
+}
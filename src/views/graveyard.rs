@@ -0,0 +1,69 @@
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear},
+    Frame,
+};
+
+use crate::app::{App, Graveyard};
+use crate::mouse::HitTarget;
+use crate::views::Render;
+
+use super::finder::{list_rows, popup_area};
+
+/// Widest the path column grows before the commit columns.
+const MAX_PATH_WIDTH: usize = 40;
+
+/// Draw the deleted files with the commit that deleted each and its last version.
+pub fn draw(f: &mut Frame, app: &App, graveyard: &Graveyard, render: &mut Render) {
+    let area = popup_area(f.area());
+    f.render_widget(Clear, area);
+
+    let title = format!(
+        " GRAVEYARD │ {} deleted │ Enter restores the last version │ Esc closes ",
+        graveyard.graves.len()
+    );
+    let block = Block::default().borders(Borders::ALL).title(title);
+    let list_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let path_width = graveyard
+        .graves
+        .iter()
+        .map(|g| g.path.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(MAX_PATH_WIDTH);
+    let commits = &app.history().commits;
+    let rows = list_rows(
+        f,
+        list_area,
+        (&graveyard.graves, graveyard.selected),
+        |grave, is_selected| {
+            let (deleted, last) = (
+                commits.get(grave.deleted_at)?,
+                commits.get(grave.last_seen)?,
+            );
+            let (base, sha) = if is_selected {
+                let style = Style::default().fg(Color::Black).bg(Color::Cyan);
+                (style, style)
+            } else {
+                (
+                    Style::default().fg(Color::Gray),
+                    Style::default().fg(Color::Yellow),
+                )
+            };
+            let line = Line::from(vec![
+                Span::styled(format!(" {:<path_width$}  ", grave.path), base),
+                Span::styled("last ", base),
+                Span::styled(last.short_oid.clone(), sha),
+                Span::styled("  deleted in ", base),
+                Span::styled(deleted.short_oid.clone(), sha),
+                Span::styled(format!(" {}", deleted.summary), base),
+            ]);
+            Some(line.style(base))
+        },
+        HitTarget::GraveyardItem,
+    );
+    render.hit_boxes.extend(rows);
+}
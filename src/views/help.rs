@@ -0,0 +1,55 @@
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::keymap::Action;
+
+use super::finder::popup_area;
+
+/// Width of the key column.
+const KEYS_WIDTH: usize = 16;
+
+/// Draw every action available in the current view with its keys, straight from the keymap.
+pub fn draw(f: &mut Frame, app: &App, scroll: usize) {
+    let area = popup_area(f.area());
+    f.render_widget(Clear, area);
+
+    let view = app.view();
+    let keymap = &app.config().keymap;
+    let lines: Vec<Line> = Action::ALL
+        .iter()
+        .filter(|action| action.available_in(view))
+        .map(|&action| {
+            let keys = keymap.keys_for(action);
+            let (keys, key_style) = if keys.is_empty() {
+                (
+                    format!(":{}", action.name()),
+                    Style::default().fg(Color::DarkGray),
+                )
+            } else {
+                (keys.join(", "), Style::default().fg(Color::Yellow))
+            };
+            Line::from(vec![
+                Span::styled(format!(" {keys:<KEYS_WIDTH$} "), key_style),
+                Span::styled(action.description(), Style::default().fg(Color::Gray)),
+            ])
+        })
+        .collect();
+
+    let block = Block::default().borders(Borders::ALL).title(format!(
+        " HELP │ {} │ ↑/↓ scroll │ Esc closes ",
+        view.name()
+    ));
+    let visible = block.inner(area).height as usize;
+    let scroll = scroll.min(lines.len().saturating_sub(visible));
+
+    #[allow(clippy::cast_possible_truncation)]
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((scroll as u16, 0));
+    f.render_widget(paragraph, area);
+}
@@ -0,0 +1,88 @@
+use crate::app::App;
+use crate::keymap::Action;
+use crate::views::ViewMode;
+
+/// Space-separated `[key]label` hints for the bound actions among `actions`.
+pub fn hints(app: &App, actions: &[Action]) -> String {
+    let keymap = &app.config().keymap;
+    actions
+        .iter()
+        .filter_map(|&action| keymap.hint(action))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// One hint for two opposite actions, such as `[+/-]zoom`.
+pub fn pair(app: &App, first: Action, second: Action, label: &str) -> String {
+    group(app, &[first, second], label)
+}
+
+/// One hint for related actions, such as `[h/j/k/l]focus`.
+pub fn group(app: &App, actions: &[Action], label: &str) -> String {
+    let keymap = &app.config().keymap;
+    let keys: Vec<String> = actions.iter().filter_map(|&a| keymap.key_for(a)).collect();
+    if keys.is_empty() {
+        return String::new();
+    }
+    format!("[{}]{label}", keys.join("/"))
+}
+
+/// Hint for moving and using the keyboard focus.
+pub fn focus(app: &App) -> String {
+    let moves = group(
+        app,
+        &[
+            Action::FocusLeft,
+            Action::FocusDown,
+            Action::FocusUp,
+            Action::FocusRight,
+        ],
+        "focus",
+    );
+    format!("{moves} {}", hints(app, &[Action::Activate]))
+}
+
+/// Hint for the view switching keys, collapsed to a range like `[1-6]views`.
+pub fn views(app: &App) -> String {
+    let keymap = &app.config().keymap;
+    let first = keymap.key_for(Action::View(ViewMode::Terrain));
+    let last = keymap.key_for(Action::View(ViewMode::Surgery));
+    match (first, last) {
+        (Some(a), Some(b)) => format!("[{a}-{b}]views"),
+        _ => String::new(),
+    }
+}
+
+/// Keys typed so far of an unfinished sequence, shown while waiting for the rest.
+pub fn pending(app: &App) -> String {
+    let keys = app.pending_keys();
+    if keys.is_empty() {
+        return String::new();
+    }
+    let typed: Vec<String> = keys.iter().map(ToString::to_string).collect();
+    format!(" [{}…]", typed.join(" "))
+}
+
+/// Undo hint, shown only while there is a restore to undo.
+pub fn undo(app: &App) -> String {
+    if !app.can_undo_restore() {
+        return String::new();
+    }
+    app.config()
+        .keymap
+        .hint(Action::Undo)
+        .map_or_else(String::new, |hint| format!(" {hint}"))
+}
+
+/// Size of the restore selection, with the restore key when no file is selected to show it.
+pub fn selection(app: &App) -> String {
+    if app.selection().next().is_none() {
+        return String::new();
+    }
+    let count = app.restore_paths().len();
+    if app.selected_file().is_some() {
+        format!(" [{count} selected]")
+    } else {
+        format!(" [{count} selected] {}", hints(app, &[Action::Restore]))
+    }
+}
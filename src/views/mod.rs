@@ -1,11 +1,30 @@
+mod decorations;
+mod finder;
+mod graveyard;
+mod help;
+mod hints;
+mod palette;
+mod prompt;
+mod report;
+mod restore;
 mod seismic;
+mod syntax;
 mod terrain;
+mod timeline;
+mod viewer;
 
-use ratatui::Frame;
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+    Frame,
+};
 
-use crate::app::App;
+use crate::app::{App, MarkPrompt};
+use crate::config::ColorDepth;
 use crate::mouse::HitBox;
 
+pub use seismic::{Aggregate, SortMode, Zoom};
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum ViewMode {
     #[default]
@@ -49,6 +68,18 @@ impl ViewMode {
         }
     }
 
+    /// Look up a view by its case-insensitive name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        if name == "constellation" {
+            return Some(ViewMode::Constellation);
+        }
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|mode| mode.name().to_lowercase() == name)
+    }
+
     pub fn from_index(i: usize) -> Self {
         Self::ALL.get(i).copied().unwrap_or_default()
     }
@@ -68,12 +99,18 @@ impl ViewMode {
 /// Result of rendering a view, containing hit boxes for mouse interaction.
 pub struct Render {
     pub hit_boxes: Vec<HitBox>,
+    /// Paths of the seismic grid rows in display order, when Seismic was drawn.
+    pub seismic_rows: Vec<String>,
+    /// Lines of file content that fit in the viewer, when it was drawn.
+    pub viewer_height: Option<usize>,
 }
 
 impl Render {
     pub fn new() -> Self {
         Self {
             hit_boxes: Vec::new(),
+            seismic_rows: Vec::new(),
+            viewer_height: None,
         }
     }
 }
@@ -86,12 +123,83 @@ impl Default for Render {
 
 /// Draw the current view.
 pub fn draw(f: &mut Frame, app: &App) -> Render {
-    match app.view() {
+    let mut render = match app.view() {
         ViewMode::Seismic => seismic::draw(f, app),
         ViewMode::Terrain
         | ViewMode::Strata
         | ViewMode::Flow
         | ViewMode::Constellation
         | ViewMode::Surgery => terrain::draw(f, app),
+    };
+
+    if let Some(search) = app.commit_search().filter(|s| s.editing) {
+        prompt::draw(f, "Search commits:", &search.query);
+    }
+
+    match app.mark_prompt() {
+        Some(MarkPrompt::Set) => prompt::draw(f, "Mark commit as (letter):", ""),
+        Some(MarkPrompt::Jump) => prompt::draw(f, "Jump to mark (letter):", ""),
+        None => {}
+    }
+
+    if let Some(pickaxe) = app.pickaxe().filter(|p| p.editing) {
+        prompt::draw(f, "Pickaxe (text or /regex/):", &pickaxe.query);
+    }
+
+    if let Some(viewer) = app.viewer() {
+        render.hit_boxes.clear();
+        render.viewer_height = Some(viewer::draw(f, app, viewer));
+    }
+
+    if let Some(prompt) = app.ref_prompt() {
+        prompt::draw(f, prompt.kind.prompt(), &prompt.input);
+    }
+
+    if let Some(finder) = app.finder() {
+        render.hit_boxes.clear();
+        finder::draw(f, finder, &mut render);
+    }
+
+    if let Some(palette) = app.palette() {
+        render.hit_boxes.clear();
+        palette::draw(f, app, palette, &mut render);
+    }
+
+    if let Some(graveyard) = app.graveyard() {
+        render.hit_boxes.clear();
+        graveyard::draw(f, app, graveyard, &mut render);
+    }
+
+    if let Some(panel) = app.report() {
+        render.hit_boxes.clear();
+        report::draw(f, panel);
+    }
+
+    if let Some(prompt) = app.restore_prompt() {
+        render.hit_boxes.clear();
+        restore::draw(f, prompt);
+    }
+
+    if let Some(scroll) = app.help_scroll() {
+        render.hit_boxes.clear();
+        help::draw(f, app, scroll);
+    }
+
+    adapt_colors(f.buffer_mut(), app.config().color_depth);
+    render
+}
+
+/// Downgrade every drawn color to what the terminal supports. Without color,
+/// highlighted backgrounds turn into reverse video so selections stay visible.
+fn adapt_colors(buffer: &mut Buffer, depth: ColorDepth) {
+    if depth == ColorDepth::TrueColor {
+        return;
+    }
+    for cell in &mut buffer.content {
+        if depth == ColorDepth::None && cell.bg != Color::Reset {
+            cell.modifier.insert(Modifier::REVERSED);
+        }
+        cell.fg = depth.adapt(cell.fg);
+        cell.bg = depth.adapt(cell.bg);
     }
 }
@@ -0,0 +1,73 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, Palette};
+use crate::keymap::Action;
+use crate::mouse::HitTarget;
+use crate::views::Render;
+
+use super::finder::{highlight, list_rows, popup_area};
+
+/// Width of the command name column.
+const NAME_WIDTH: usize = 20;
+/// Width of the key binding column.
+const KEYS_WIDTH: usize = 12;
+
+/// Draw the command palette as a centered popup listing actions, keys and descriptions.
+pub fn draw(f: &mut Frame, app: &App, palette: &Palette, render: &mut Render) {
+    let area = popup_area(f.area());
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" COMMANDS │ name [argument] ");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(inner);
+    let input_area = chunks.first().copied().unwrap_or_default();
+    let list_area = chunks.get(1).copied().unwrap_or_default();
+
+    let input = Line::from(vec![
+        Span::styled(": ", Style::default().fg(Color::Cyan)),
+        Span::raw(palette.query.as_str()),
+        Span::styled("▏", Style::default().fg(Color::Cyan)),
+    ]);
+    f.render_widget(Paragraph::new(input), input_area);
+
+    let keymap = &app.config().keymap;
+    let rows = list_rows(
+        f,
+        list_area,
+        (&palette.matches, palette.selected),
+        |m, is_selected| {
+            let action = Action::from_name(&m.text)?;
+            let mut line = highlight(m, is_selected);
+            let style = line.style;
+
+            let pad = NAME_WIDTH.saturating_sub(m.text.chars().count());
+            let keys = keymap.keys_for(action).join(" ");
+            line.spans.push(Span::styled(" ".repeat(pad), style));
+            line.spans.push(Span::styled(
+                format!("{keys:<KEYS_WIDTH$} "),
+                style.fg(if is_selected {
+                    Color::Black
+                } else {
+                    Color::Yellow
+                }),
+            ));
+            line.spans.push(Span::styled(action.description(), style));
+            Some(line)
+        },
+        HitTarget::PaletteItem,
+    );
+    render.hit_boxes.extend(rows);
+}
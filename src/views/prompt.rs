@@ -0,0 +1,114 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, Pickaxe};
+use crate::keymap::Action;
+use crate::views::hints;
+
+/// Draw a one-line input prompt over the bottom row of the screen.
+pub fn draw(f: &mut Frame, label: &str, text: &str) {
+    let area = f.area();
+    if area.height == 0 {
+        return;
+    }
+    let rect = Rect::new(area.x, area.y + area.height - 1, area.width, 1);
+    f.render_widget(Clear, rect);
+
+    let line = Line::from(vec![
+        Span::styled(format!(" {label} "), Style::default().fg(Color::Cyan)),
+        Span::raw(text.to_string()),
+        Span::styled("▏", Style::default().fg(Color::Cyan)),
+    ]);
+    f.render_widget(Paragraph::new(line), rect);
+}
+
+/// Status-bar fragment describing the active commit or pickaxe search, if any,
+/// followed by the path filter and the last command's notice.
+pub fn search_status(app: &App) -> String {
+    let path = app
+        .path_filter()
+        .map_or_else(String::new, |prefix| format!(" [path: {prefix}]"));
+    let playback = app.playback();
+    let playing = if playback.is_playing() {
+        format!(" [▶ {:.1} commits/s]", playback.speed)
+    } else {
+        String::new()
+    };
+    let marks: String = app
+        .marks_at_current()
+        .into_iter()
+        .map(|(letter, note)| match note {
+            Some(note) => format!(" [mark {letter}: {note}]"),
+            None => format!(" [mark {letter}]"),
+        })
+        .collect();
+    let notice = app
+        .notice()
+        .map_or_else(String::new, |notice| format!(" │ {notice}"));
+    format!("{}{marks}{path}{playing}{notice}", match_status(app))
+}
+
+fn match_status(app: &App) -> String {
+    if let Some(pickaxe) = app.pickaxe() {
+        return pickaxe_status(app, pickaxe);
+    }
+    if let Some(trace) = app.line_trace() {
+        let position = app
+            .search_position()
+            .map_or_else(String::new, |(n, _)| format!("{n}/"));
+        return format!(
+            " {}trace {} of {} {position}{} commits",
+            match_keys(app),
+            trace.label,
+            trace.path,
+            trace.commits.len()
+        );
+    }
+    let Some(search) = app.commit_search() else {
+        return String::new();
+    };
+    if search.editing {
+        return String::new();
+    }
+    let keys = match_keys(app);
+    match app.search_position() {
+        Some((n, total)) => format!(" {keys}match {n}/{total} '{}'", search.query),
+        None => format!(" {keys}{} matches '{}'", search.matches.len(), search.query),
+    }
+}
+
+fn pickaxe_status(app: &App, pickaxe: &Pickaxe) -> String {
+    if pickaxe.editing {
+        return String::new();
+    }
+    if let Some(error) = &pickaxe.error {
+        return format!(" [pickaxe: {error}]");
+    }
+    let progress = if pickaxe.done {
+        String::new()
+    } else {
+        format!(" scanning {}/{}", pickaxe.scanned, app.commit_count())
+    };
+    let scope = pickaxe
+        .scope
+        .as_deref()
+        .map_or_else(String::new, |path| format!(" in {path}"));
+    let position = app
+        .search_position()
+        .map_or_else(String::new, |(n, _)| format!("{n}/"));
+    format!(
+        " {}pickaxe '{}'{scope} {position}{} commits{progress}",
+        match_keys(app),
+        pickaxe.query,
+        pickaxe.commits.len()
+    )
+}
+
+fn match_keys(app: &App) -> String {
+    hints::pair(app, Action::NextMatch, Action::PrevMatch, "")
+}
@@ -0,0 +1,46 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::ReportPanel;
+
+use super::finder::popup_area;
+
+/// Draw the release report's Markdown, headings picked out.
+pub fn draw(f: &mut Frame, panel: &ReportPanel) {
+    let area = popup_area(f.area());
+    f.render_widget(Clear, area);
+
+    let lines: Vec<Line> = panel
+        .markdown
+        .lines()
+        .map(|line| {
+            let style = if line.starts_with('#') {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else if line.starts_with("| -") || line == "_None._" {
+                Style::default().fg(Color::DarkGray)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            Line::styled(line.to_string(), style)
+        })
+        .collect();
+
+    let block = Block::default().borders(Borders::ALL).title(format!(
+        " RELEASE REPORT │ {}..{} │ ↑/↓ scroll │ w writes Markdown │ Esc closes ",
+        panel.report.from, panel.report.to
+    ));
+    let visible = block.inner(area).height as usize;
+    let scroll = panel.scroll.min(lines.len().saturating_sub(visible));
+
+    #[allow(clippy::cast_possible_truncation)]
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((scroll as u16, 0));
+    f.render_widget(paragraph, area);
+}
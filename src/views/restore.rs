@@ -0,0 +1,201 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::{RestorePrompt, RestoreScope};
+use crate::git_ops::{CheckoutPlan, PathChange, RestorePlan, RestoreTarget};
+
+/// Width of the dialog, shrunk to fit small terminals.
+const WIDTH: u16 = 72;
+/// Changed files listed by name before the rest are summed up.
+const MAX_LISTED: usize = 8;
+
+/// Draw the restore confirmation: what changes against the working copy and how to answer.
+pub fn draw(f: &mut Frame, prompt: &RestorePrompt) {
+    let worktree = prompt.target == RestoreTarget::Worktree;
+    let mut lines = match &prompt.scope {
+        RestoreScope::File(plan) => file_lines(plan, worktree),
+        RestoreScope::Paths(plan) => paths_lines(plan, worktree),
+    };
+    lines.push(Line::default());
+    lines.extend(target_lines(prompt));
+    lines.push(Line::default());
+    let keys = if prompt.target == RestoreTarget::Path {
+        " [Enter] restore  [Tab] target  [Esc] cancel"
+    } else {
+        " [y/Enter] restore  [Tab/t] target  [n/Esc] cancel"
+    };
+    lines.push(Line::from(Span::styled(
+        keys,
+        Style::default().fg(Color::Cyan),
+    )));
+
+    let area = f.area();
+    let width = WIDTH.min(area.width);
+    #[allow(clippy::cast_possible_truncation)]
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let rect = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+    f.render_widget(Clear, rect);
+    let block = Block::default().borders(Borders::ALL).title(" RESTORE ");
+    f.render_widget(Paragraph::new(lines).block(block), rect);
+}
+
+fn file_lines(plan: &RestorePlan, worktree: bool) -> Vec<Line<'static>> {
+    let change = match &plan.current {
+        Some(current) if plan.is_binary() => Line::from(format!(
+            " Binary file: {} → {} bytes",
+            current.content.len(),
+            plan.incoming.content.len()
+        )),
+        Some(_) => Line::from(vec![
+            Span::raw(" Against the working copy: "),
+            Span::styled(
+                format!("+{}", plan.added),
+                Style::default().fg(Color::Green),
+            ),
+            Span::raw(" "),
+            Span::styled(
+                format!("-{}", plan.removed),
+                Style::default().fg(Color::Red),
+            ),
+            Span::raw(" lines"),
+        ]),
+        None => Line::from(format!(
+            " Not in the working copy; it will be created ({})",
+            plan.incoming.kind.name()
+        )),
+    };
+
+    let mut lines = vec![
+        Line::from(format!(" Restore {} from {}?", plan.path, plan.short_oid())),
+        change,
+    ];
+    if let Some(current) = plan
+        .current
+        .as_ref()
+        .filter(|c| c.kind != plan.incoming.kind)
+    {
+        lines.push(Line::from(format!(
+            " Mode: {} → {}",
+            current.kind.name(),
+            plan.incoming.kind.name()
+        )));
+    }
+    if !worktree {
+        return lines;
+    }
+    if plan.is_noop() {
+        lines.push(warning(" The working copy already matches".to_string()));
+    } else if plan.uncommitted {
+        lines.push(warning(" ⚠ This file has uncommitted changes".to_string()));
+    }
+    if plan.current.is_some() {
+        lines.push(backup_note());
+    }
+    lines
+}
+
+fn paths_lines(plan: &CheckoutPlan, worktree: bool) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(format!(
+            " Restore {} from {}?",
+            plan.specs.join(", "),
+            plan.short_oid()
+        )),
+        Line::from(format!(" {} files: {}", plan.changes.len(), plan.summary())),
+    ];
+    for (path, change) in plan.changes.iter().take(MAX_LISTED) {
+        let (marker, color) = match change {
+            PathChange::Modified => ('~', Color::Yellow),
+            PathChange::Created => ('+', Color::Green),
+            PathChange::Deleted => ('-', Color::Red),
+        };
+        lines.push(Line::styled(
+            format!("   {marker} {path}"),
+            Style::default().fg(color),
+        ));
+    }
+    if plan.changes.len() > MAX_LISTED {
+        lines.push(Line::from(format!(
+            "   … and {} more",
+            plan.changes.len() - MAX_LISTED
+        )));
+    }
+    if !worktree {
+        return lines;
+    }
+    if plan.uncommitted > 0 {
+        lines.push(warning(format!(
+            " ⚠ {} of these files have uncommitted changes",
+            plan.uncommitted
+        )));
+    }
+    if plan.count(PathChange::Created) < plan.changes.len() {
+        lines.push(backup_note());
+    }
+    lines
+}
+
+/// The chosen target, what it does, and the destination being typed for a chosen path.
+fn target_lines(prompt: &RestorePrompt) -> Vec<Line<'static>> {
+    let single = prompt.is_single_file();
+    let effect = match prompt.target {
+        RestoreTarget::Worktree => "Overwrites the working copy",
+        RestoreTarget::Index => "Stages the old version; the working copy is left alone",
+        RestoreTarget::Side if single => "Writes <path>.<sha> next to the current file",
+        RestoreTarget::Side => "Writes each file as <path>.<sha> next to the current one",
+        RestoreTarget::Path if single => "Writes to this file, relative to the current directory:",
+        RestoreTarget::Path => "Writes under this directory, relative to the current directory:",
+        RestoreTarget::Stdout => "Prints the file to stdout when git-trek exits",
+    };
+    let mut lines = vec![
+        Line::from(vec![
+            Span::raw(" Target: "),
+            Span::styled(
+                prompt.target.name(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::styled(format!(" {effect}"), Style::default().fg(Color::Gray)),
+    ];
+    if prompt.target == RestoreTarget::Path {
+        lines.push(Line::from(vec![
+            Span::raw(format!(" {}", prompt.dest)),
+            Span::styled("▏", Style::default().fg(Color::Cyan)),
+        ]));
+        if let Some(dest) = prompt.resolved_dest() {
+            lines.push(Line::styled(
+                format!(" → {}", dest.display()),
+                Style::default().fg(Color::Gray),
+            ));
+        }
+    }
+    lines
+}
+
+fn warning(text: String) -> Line<'static> {
+    Line::from(Span::styled(
+        text,
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn backup_note() -> Line<'static> {
+    Line::from(Span::styled(
+        " The current content is backed up under .git/git-trek/backups/",
+        Style::default().fg(Color::Gray),
+    ))
+}
@@ -1,27 +1,55 @@
 mod activity;
+mod order;
 mod render;
 
 use std::collections::HashSet;
+use std::ops::Range;
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    widgets::{Block, Borders},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
-use crate::app::App;
+use crate::app::{App, LineTrace, Pickaxe};
+use crate::config::Config;
+use crate::data::{Commit, RefKind};
+use crate::views::decorations::label_refs;
 use crate::views::Render;
 
-struct GridContext {
-    start_commit: usize,
-    end_commit: usize,
+use super::zoom::{Aggregate, Zoom};
+
+pub use order::SortMode;
+
+struct GridContext<'a> {
+    buckets: &'a [Range<usize>],
     current: usize,
+    /// Pinned compare base; the current column then shows the change since it.
+    base: Option<usize>,
+    zoom: Zoom,
+    aggregate: Aggregate,
+    pickaxe: Option<&'a Pickaxe>,
+    trace: Option<&'a LineTrace>,
+    /// Per shown bucket, the tagged commit it holds; a release separator is drawn
+    /// just before it.
+    tags: Vec<Option<&'a Commit>>,
+    config: &'a Config,
     file_col: Rect,
     grid_col: Rect,
 }
 
 pub fn draw(f: &mut Frame, area: Rect, app: &App, render: &mut Render) {
-    let title = format!(" SEISMIC MONITOR @ {} ", app.commit_label());
+    let zoom = app.seismic_zoom();
+    let aggregate = app.seismic_aggregate();
+    let title = format!(
+        " SEISMIC MONITOR @ {} │ {} ({}) │ by {} ",
+        app.commit_label(),
+        zoom.name(),
+        aggregate.name(),
+        app.seismic_sort().name()
+    );
 
     let block = Block::default().borders(Borders::ALL).title(title);
     let inner = block.inner(area);
@@ -43,43 +71,98 @@ pub fn draw(f: &mut Frame, area: Rect, app: &App, render: &mut Render) {
     let file_col = grid_area.first().copied().unwrap_or_default();
     let grid_col = grid_area.get(1).copied().unwrap_or_default();
 
-    let cell_width = 2u16;
-    let visible_commits = if cell_width == 0 {
-        0
-    } else {
-        (grid_col.width / cell_width) as usize
-    };
+    let visible_buckets = grid_col.width.checked_div(zoom.cell_width()).unwrap_or(0) as usize;
 
-    if visible_commits == 0 {
+    if visible_buckets == 0 {
         return;
     }
 
-    let total = commits.len();
+    let buckets = zoom.buckets(history);
     let current = app.commit_idx();
-    let half_visible = visible_commits / 2;
-
-    let start_commit = calc_start_commit(total, visible_commits, current, half_visible);
-    let end_commit = (start_commit + visible_commits).min(total);
-    let all_files = collect_files(
+    let current_bucket = buckets
+        .iter()
+        .position(|b| b.contains(&current))
+        .unwrap_or(0);
+    let half_visible = visible_buckets / 2;
+
+    let start_bucket = calc_start(buckets.len(), visible_buckets, current_bucket, half_visible);
+    let end_bucket = (start_bucket + visible_buckets).min(buckets.len());
+    let shown = buckets.get(start_bucket..end_bucket).unwrap_or_default();
+    let start_commit = shown.first().map_or(0, |b| b.start);
+    let end_commit = shown.last().map_or(0, |b| b.end);
+
+    let mut all_files = collect_files(
         history,
         start_commit,
         end_commit,
         app.seismic_filter_inactive(),
     );
-    let sorted_files = activity::sort_by_activity(history, &all_files, start_commit, end_commit);
+    all_files.retain(|path| app.passes_path_filter(path));
+    let sorted_files = order::sort_files(
+        history,
+        &all_files,
+        (start_commit, end_commit),
+        app.seismic_sort(),
+        current,
+    );
+    let rows = order::build_rows(sorted_files, app.seismic_grouped(), app.seismic_collapsed());
 
     let ctx = GridContext {
-        start_commit,
-        end_commit,
+        buckets: shown,
         current,
+        base: app.compare_base_idx(),
+        zoom,
+        aggregate,
+        pickaxe: app.pickaxe(),
+        trace: app.line_trace(),
+        tags: shown
+            .iter()
+            .map(|bucket| commits.get(bucket.clone())?.iter().find(|c| c.is_tagged()))
+            .collect(),
+        config: app.config(),
         file_col,
         grid_col,
     };
 
-    render::draw_rows(f, app, &sorted_files, history, &ctx, render);
+    render.seismic_rows = rows.iter().map(|r| r.path().to_string()).collect();
+    draw_mark_row(f, app, &ctx);
+    render::draw_rows(f, app, &rows, history, &ctx, render);
+}
+
+/// Show bookmark letters on the row above the columns that contain them, and tag
+/// names over tagged columns.
+fn draw_mark_row(f: &mut Frame, app: &App, ctx: &GridContext) {
+    let width = ctx.zoom.cell_width() as usize;
+    let marks = app.marked_commits();
+    let mut line: Vec<Span> = ctx
+        .buckets
+        .iter()
+        .flat_map(|bucket| {
+            let letter = marks
+                .iter()
+                .find(|(idx, _)| bucket.contains(idx))
+                .map_or(Span::raw(" "), |(_, letter)| {
+                    Span::styled(letter.to_string(), Style::default().fg(Color::Green))
+                });
+            std::iter::once(letter)
+                .chain(std::iter::repeat_n(Span::raw(" "), width.saturating_sub(1)))
+        })
+        .collect();
+    let tagged: Vec<(usize, &Commit)> = ctx
+        .tags
+        .iter()
+        .enumerate()
+        .filter_map(|(i, tag)| Some((i * width, (*tag)?)))
+        .collect();
+    label_refs(&mut line, &tagged, " ", |r| r.kind == RefKind::Tag);
+
+    f.render_widget(
+        Paragraph::new(Line::from(line)),
+        Rect::new(ctx.grid_col.x, ctx.grid_col.y, ctx.grid_col.width, 1),
+    );
 }
 
-fn calc_start_commit(total: usize, visible: usize, current: usize, half: usize) -> usize {
+fn calc_start(total: usize, visible: usize, current: usize, half: usize) -> usize {
     if total <= visible || current < half {
         return 0;
     }
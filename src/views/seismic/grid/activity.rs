@@ -44,24 +44,8 @@ pub fn has_activity(history: &History, path: &str, start: usize, end: usize) ->
 }
 
 fn calc_activity_score(history: &History, path: &str, start: usize, end: usize) -> u64 {
-    let Some(file_hist) = history.files.get(path) else {
-        return 0;
-    };
-
-    let mut score = 0u64;
-    for i in start..end {
-        let prev_lines = if i == 0 {
-            None
-        } else {
-            file_hist.lines_at(i - 1)
-        };
-        let curr_lines = file_hist.lines_at(i);
-
-        if let (Some(prev), Some(curr)) = (prev_lines, curr_lines) {
-            score += prev.abs_diff(curr) as u64;
-        } else if prev_lines.is_some() || curr_lines.is_some() {
-            score += 10;
-        }
-    }
-    score
+    history
+        .files
+        .get(path)
+        .map_or(0, |file_hist| file_hist.activity_score(start, end))
 }
@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+
+use crate::data::{parent_dir, FileHistory, History};
+
+use super::activity;
+
+/// Ordering applied to the rows of the seismic grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SortMode {
+    #[default]
+    Activity,
+    Path,
+    Size,
+    FirstSeen,
+    LastTouched,
+}
+
+impl SortMode {
+    pub const ALL: [SortMode; 5] = [
+        SortMode::Activity,
+        SortMode::Path,
+        SortMode::Size,
+        SortMode::FirstSeen,
+        SortMode::LastTouched,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SortMode::Activity => "activity",
+            SortMode::Path => "path",
+            SortMode::Size => "size",
+            SortMode::FirstSeen => "first seen",
+            SortMode::LastTouched => "last touched",
+        }
+    }
+
+    #[must_use]
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|m| *m == self).unwrap_or(0);
+        Self::ALL
+            .get((i + 1) % Self::ALL.len())
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// A row of the seismic grid: a directory header or a file.
+pub enum Row {
+    Dir {
+        path: String,
+        files: Vec<String>,
+        collapsed: bool,
+    },
+    File {
+        path: String,
+        indented: bool,
+    },
+}
+
+impl Row {
+    pub fn path(&self) -> &str {
+        match self {
+            Row::Dir { path, .. } | Row::File { path, .. } => path,
+        }
+    }
+}
+
+pub fn sort_files(
+    history: &History,
+    files: &[String],
+    window: (usize, usize),
+    mode: SortMode,
+    current: usize,
+) -> Vec<String> {
+    let (start, end) = window;
+    if mode == SortMode::Activity {
+        return activity::sort_by_activity(history, files, start, end);
+    }
+
+    let mut sorted = files.to_vec();
+    sorted.sort_by_cached_key(|path| {
+        let file = history.files.get(path);
+        let key = match mode {
+            SortMode::Size => {
+                let lines = file.and_then(|f| f.lines_at(current)).unwrap_or(0);
+                usize::MAX - lines
+            }
+            SortMode::FirstSeen => file.and_then(FileHistory::first_seen).unwrap_or(usize::MAX),
+            SortMode::LastTouched => file
+                .and_then(FileHistory::last_touched)
+                .unwrap_or(usize::MAX),
+            SortMode::Activity | SortMode::Path => 0,
+        };
+        (key, path.clone())
+    });
+    sorted
+}
+
+/// Arrange sorted files into rows, optionally grouped under their parent directory.
+pub fn build_rows(files: Vec<String>, grouped: bool, collapsed: &HashSet<String>) -> Vec<Row> {
+    if !grouped {
+        return files
+            .into_iter()
+            .map(|path| Row::File {
+                path,
+                indented: false,
+            })
+            .collect();
+    }
+
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    for path in files {
+        let dir = parent_dir(&path);
+        match groups.iter_mut().find(|(d, _)| *d == dir) {
+            Some((_, members)) => members.push(path),
+            None => groups.push((dir, vec![path])),
+        }
+    }
+
+    let mut rows = Vec::new();
+    for (dir, members) in groups {
+        let is_collapsed = collapsed.contains(&dir);
+        let children: Vec<Row> = if is_collapsed {
+            Vec::new()
+        } else {
+            members
+                .iter()
+                .map(|path| Row::File {
+                    path: path.clone(),
+                    indented: true,
+                })
+                .collect()
+        };
+        rows.push(Row::Dir {
+            path: dir,
+            files: members,
+            collapsed: is_collapsed,
+        });
+        rows.extend(children);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,112 @@
+use super::*;
+use crate::error::Result;
+use crate::views::seismic::testing::history;
+
+/// Newest first: `a.rs` grows at the newest commit, `c.rs` is created there,
+/// and `b.rs` and `d.rs` never change.
+fn sample() -> Result<History> {
+    history(
+        &[0; 3],
+        &[
+            ("b.rs", &[Some(10), Some(10), Some(10)]),
+            ("a.rs", &[Some(30), Some(10), Some(10)]),
+            ("d.rs", &[Some(10), Some(10), Some(10)]),
+            ("c.rs", &[Some(5), None, None]),
+        ],
+    )
+}
+
+fn sorted(history: &History, mode: SortMode) -> Vec<String> {
+    let files: Vec<String> = ["b.rs", "a.rs", "d.rs", "c.rs"]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    sort_files(history, &files, (0, 3), mode, 0)
+}
+
+#[test]
+fn sort_modes_order_rows_and_break_ties_by_path() -> Result<()> {
+    let history = sample()?;
+    assert_eq!(
+        sorted(&history, SortMode::Path),
+        ["a.rs", "b.rs", "c.rs", "d.rs"]
+    );
+    assert_eq!(
+        sorted(&history, SortMode::Activity),
+        ["a.rs", "c.rs", "b.rs", "d.rs"]
+    );
+    assert_eq!(
+        sorted(&history, SortMode::Size),
+        ["a.rs", "b.rs", "d.rs", "c.rs"]
+    );
+    assert_eq!(
+        sorted(&history, SortMode::FirstSeen),
+        ["c.rs", "a.rs", "b.rs", "d.rs"]
+    );
+    assert_eq!(
+        sorted(&history, SortMode::LastTouched),
+        ["a.rs", "c.rs", "b.rs", "d.rs"]
+    );
+    Ok(())
+}
+
+#[test]
+fn sort_modes_cycle_through_all() {
+    let mut mode = SortMode::default();
+    for expected in SortMode::ALL.iter().skip(1).chain(SortMode::ALL.first()) {
+        mode = mode.next();
+        assert_eq!(mode, *expected);
+    }
+}
+
+fn describe(rows: &[Row]) -> Vec<String> {
+    rows.iter()
+        .map(|row| match row {
+            Row::Dir {
+                path,
+                files,
+                collapsed,
+            } => format!("dir {path} {} {collapsed}", files.len()),
+            Row::File { path, indented } => format!("file {path} {indented}"),
+        })
+        .collect()
+}
+
+fn files(paths: &[&str]) -> Vec<String> {
+    paths.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn ungrouped_rows_keep_the_sorted_order() {
+    let rows = build_rows(files(&["src/b.rs", "a.rs"]), false, &HashSet::new());
+    assert_eq!(describe(&rows), ["file src/b.rs false", "file a.rs false"]);
+}
+
+#[test]
+fn grouped_rows_follow_the_first_file_of_each_directory() {
+    let sorted = files(&["src/b.rs", "lib.rs", "src/a.rs", "docs/x.md"]);
+    let rows = build_rows(sorted, true, &HashSet::new());
+    assert_eq!(
+        describe(&rows),
+        [
+            "dir src/ 2 false",
+            "file src/b.rs true",
+            "file src/a.rs true",
+            "dir ./ 1 false",
+            "file lib.rs true",
+            "dir docs/ 1 false",
+            "file docs/x.md true",
+        ]
+    );
+}
+
+#[test]
+fn collapsed_groups_hide_their_files_but_keep_them_for_the_header() {
+    let sorted = files(&["src/b.rs", "lib.rs", "src/a.rs"]);
+    let collapsed = HashSet::from(["src/".to_string()]);
+    let rows = build_rows(sorted, true, &collapsed);
+    assert_eq!(
+        describe(&rows),
+        ["dir src/ 2 true", "dir ./ 1 false", "file lib.rs true"]
+    );
+}
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -10,14 +12,19 @@ use crate::app::App;
 use crate::mouse::{HitBox, HitTarget};
 use crate::views::Render;
 
-use super::super::intensity::{format_cell, Intensity};
+use super::super::intensity::{format_cell, format_delta, line_delta, Intensity};
+use super::super::zoom::Zoom;
+use super::order::Row;
 use super::GridContext;
 use crate::data::History;
 
+/// Drawn in the last cell of the column before one that holds a tagged commit.
+const TAG_SEPARATOR: &str = "│";
+
 pub fn draw_rows(
     f: &mut Frame,
     app: &App,
-    files: &[String],
+    rows: &[Row],
     history: &History,
     ctx: &GridContext,
     render: &mut Render,
@@ -26,25 +33,72 @@ pub fn draw_rows(
     let max_rows = (ctx.file_col.height.saturating_sub(1)) as usize;
     let scroll_offset = app.seismic_scroll();
 
-    for (row_idx, path) in files.iter().skip(scroll_offset).take(max_rows).enumerate() {
+    for (row_idx, row) in rows.iter().skip(scroll_offset).take(max_rows).enumerate() {
         #[allow(clippy::cast_possible_truncation)]
         let y = start_y + row_idx as u16;
+        let name_rect = Rect::new(ctx.file_col.x, y, ctx.file_col.width, 1);
 
-        draw_file_name(f, app, path, ctx.file_col, y, render);
-        draw_grid_cells(f, history, path, ctx, y, render);
+        match row {
+            Row::Dir {
+                path,
+                files,
+                collapsed,
+            } => {
+                draw_dir_name(f, app, path, *collapsed, name_rect, render);
+                draw_grid_cells(f, app, history, files, ctx, y, render);
+            }
+            Row::File { path, indented } => {
+                draw_file_name(f, app, path, *indented, name_rect, render);
+                draw_grid_cells(f, app, history, std::slice::from_ref(path), ctx, y, render);
+            }
+        }
     }
 }
 
+fn draw_dir_name(
+    f: &mut Frame,
+    app: &App,
+    dir: &str,
+    collapsed: bool,
+    dir_rect: Rect,
+    render: &mut Render,
+) {
+    let marker = if collapsed { '▸' } else { '▾' };
+    let max = (dir_rect.width as usize).saturating_sub(3);
+    let display_name = format!("{marker} {}", truncate_path(dir.trim_end_matches('/'), max));
+    let hit_box = HitBox::new(dir_rect, HitTarget::SeismicDir(dir.to_string()));
+    let color = if app.is_path_selected(dir) {
+        Color::Cyan
+    } else {
+        Color::White
+    };
+    let style = emphasize(
+        app,
+        &hit_box,
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    );
+
+    f.render_widget(Paragraph::new(Line::styled(display_name, style)), dir_rect);
+    render.hit_boxes.push(hit_box);
+}
+
 fn draw_file_name(
     f: &mut Frame,
     app: &App,
     path: &str,
-    file_col: Rect,
-    y: u16,
+    indented: bool,
+    file_rect: Rect,
     render: &mut Render,
 ) {
-    let display_name = truncate_path(path, (file_col.width as usize).saturating_sub(1));
-    let is_selected = app.selected_file() == Some(path);
+    let (indent, name) = if indented {
+        ("  ", path.rsplit('/').next().unwrap_or(path))
+    } else {
+        ("", path)
+    };
+    let max = (file_rect.width as usize).saturating_sub(1 + indent.len());
+    let display_name = format!("{indent}{}", truncate_path(name, max));
+    let hit_box = HitBox::new(file_rect, HitTarget::File(path.to_string()));
+    let is_selected = app.selected_file() == Some(path) || app.is_path_selected(path);
     let name_style = if is_selected {
         Style::default()
             .fg(Color::Cyan)
@@ -53,52 +107,130 @@ fn draw_file_name(
         Style::default().fg(Color::Gray)
     };
 
-    let file_rect = Rect::new(file_col.x, y, file_col.width, 1);
+    let name_style = emphasize(app, &hit_box, name_style);
+
     f.render_widget(
         Paragraph::new(Line::styled(display_name, name_style)),
         file_rect,
     );
-    render
-        .hit_boxes
-        .push(HitBox::new(file_rect, HitTarget::File(path.to_string())));
+    render.hit_boxes.push(hit_box);
 }
 
 fn draw_grid_cells(
     f: &mut Frame,
+    app: &App,
     history: &History,
-    path: &str,
+    paths: &[String],
     ctx: &GridContext,
     y: u16,
     render: &mut Render,
 ) {
+    let width = ctx.zoom.cell_width();
+    let colors = &ctx.config.seismic_colors;
     let mut row_line = Vec::new();
-    for (i, _commit) in history
-        .commits
-        .iter()
-        .enumerate()
-        .take(ctx.end_commit)
-        .skip(ctx.start_commit)
-    {
-        let intensity = Intensity::calc(history, path, i);
-        let is_current = i == ctx.current;
-        let (cell, style) = format_cell(intensity, is_current);
-        row_line.push(Span::styled(cell, style));
+
+    for (col_idx, bucket) in ctx.buckets.iter().enumerate() {
+        let is_current = bucket.contains(&ctx.current);
+        let base = ctx.base.filter(|_| is_current);
+        let intensity = paths.iter().fold(Intensity::None, |acc, path| {
+            acc.strongest(match base {
+                Some(base) => Intensity::calc_between(
+                    history,
+                    path,
+                    ctx.current,
+                    Some(base),
+                    &ctx.config.intensity,
+                ),
+                None => Intensity::calc_bucket(
+                    history,
+                    path,
+                    bucket,
+                    ctx.aggregate,
+                    &ctx.config.intensity,
+                ),
+            })
+        });
+        let (cell, style) = if ctx.zoom == Zoom::Detail {
+            let delta = paths
+                .iter()
+                .filter_map(|path| match base {
+                    Some(base) => history.files.get(path)?.delta_at(ctx.current, Some(base)),
+                    None => line_delta(history, path, bucket.start),
+                })
+                .reduce(|a, b| a + b);
+            format_delta(intensity, delta, is_current, width as usize, colors)
+        } else {
+            format_cell(intensity, is_current, width as usize, colors)
+        };
+        let style = if is_pickaxe_hit(ctx, paths, bucket) {
+            style.fg(Color::Black).bg(Color::Yellow)
+        } else if is_trace_hit(ctx, paths, bucket) {
+            style.fg(Color::Black).bg(Color::Magenta)
+        } else {
+            style
+        };
+        let style = if ctx.base.is_some_and(|b| bucket.contains(&b)) {
+            style.add_modifier(Modifier::UNDERLINED)
+        } else {
+            style
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        let cell_x = ctx.grid_col.x + (col_idx as u16 * width);
+        let target = if bucket.len() > 1 {
+            HitTarget::SeismicBucket(bucket.start)
+        } else {
+            HitTarget::SeismicCell(bucket.start)
+        };
+        let hit_box = HitBox::new(Rect::new(cell_x, y, width, 1), target);
+        let style = emphasize(app, &hit_box, style);
+        if ctx.tags.get(col_idx + 1).is_some_and(Option::is_some) {
+            // The padding before a tagged column becomes a release separator.
+            let body: String = cell
+                .chars()
+                .take(width.saturating_sub(1) as usize)
+                .collect();
+            row_line.push(Span::styled(body, style));
+            row_line.push(Span::styled(TAG_SEPARATOR, style.fg(Color::Yellow)));
+        } else {
+            row_line.push(Span::styled(cell, style));
+        }
+        render.hit_boxes.push(hit_box);
     }
+
     f.render_widget(
         Paragraph::new(Line::from(row_line)),
         Rect::new(ctx.grid_col.x, y, ctx.grid_col.width, 1),
     );
+}
 
-    for (col_idx, i) in (ctx.start_commit..ctx.end_commit).enumerate() {
-        #[allow(clippy::cast_possible_truncation)]
-        let cell_x = ctx.grid_col.x + (col_idx as u16 * 2);
-        let cell_rect = Rect::new(cell_x, y, 2, 1);
-        render
-            .hit_boxes
-            .push(HitBox::new(cell_rect, HitTarget::SeismicCell(i)));
+/// Hovered or focused elements are drawn in reverse video.
+fn emphasize(app: &App, hit_box: &HitBox, style: Style) -> Style {
+    if app.is_emphasized(hit_box) {
+        style.add_modifier(Modifier::REVERSED)
+    } else {
+        style
     }
 }
 
+fn is_pickaxe_hit(ctx: &GridContext, paths: &[String], bucket: &Range<usize>) -> bool {
+    let Some(pickaxe) = ctx.pickaxe else {
+        return false;
+    };
+    bucket
+        .clone()
+        .any(|i| paths.iter().any(|path| pickaxe.is_hit(path, i)))
+}
+
+fn is_trace_hit(ctx: &GridContext, paths: &[String], bucket: &Range<usize>) -> bool {
+    let Some(trace) = ctx.trace else {
+        return false;
+    };
+    bucket
+        .clone()
+        .any(|i| paths.iter().any(|path| trace.is_hit(path, i)))
+}
+
 fn truncate_path(path: &str, max: usize) -> String {
     if path.len() <= max {
         return path.to_string();
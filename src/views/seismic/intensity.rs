@@ -1,12 +1,10 @@
+use std::ops::Range;
+
 use ratatui::style::{Color, Style};
 
-const CLR_TREMOR: Color = Color::Rgb(60, 80, 60);
-const CLR_QUAKE: Color = Color::Rgb(120, 140, 60);
-const CLR_MAJOR: Color = Color::Rgb(200, 160, 40);
-const CLR_DISASTER: Color = Color::Rgb(220, 60, 40);
-const CLR_QUIET: Color = Color::Rgb(30, 32, 35);
-const CLR_DELETED: Color = Color::Rgb(80, 40, 40);
-const CLR_NEW: Color = Color::Rgb(40, 80, 100);
+use crate::config::{IntensityThresholds, SeismicColors};
+
+use super::zoom::Aggregate;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Intensity {
@@ -21,55 +19,175 @@ pub enum Intensity {
 }
 
 impl Intensity {
-    pub fn calc(history: &crate::data::History, path: &str, commit_idx: usize) -> Self {
+    pub fn calc(
+        history: &crate::data::History,
+        path: &str,
+        commit_idx: usize,
+        thresholds: &IntensityThresholds,
+    ) -> Self {
+        Self::calc_between(
+            history,
+            path,
+            commit_idx,
+            parent_idx(history, commit_idx),
+            thresholds,
+        )
+    }
+
+    /// Intensity of the change from `prev_idx` to `commit_idx`, such as a compare base to the cursor.
+    pub fn calc_between(
+        history: &crate::data::History,
+        path: &str,
+        commit_idx: usize,
+        prev_idx: Option<usize>,
+        thresholds: &IntensityThresholds,
+    ) -> Self {
         let Some(file_hist) = history.files.get(path) else {
             return Self::None;
         };
 
         let current_lines = file_hist.lines_at(commit_idx);
-        let prev_lines = commit_idx
-            .checked_sub(1)
-            .and_then(|i| file_hist.lines_at(i));
+        let prev_lines = prev_idx.and_then(|i| file_hist.lines_at(i));
 
         match (prev_lines, current_lines) {
             (None, None) => Self::None,
             (None, Some(_)) => Self::Created,
             (Some(_), None) => Self::Deleted,
-            (Some(old), Some(new)) => {
-                let diff = old.abs_diff(new);
-                if diff == 0 {
-                    Self::Quiet
-                } else if diff <= 10 {
-                    Self::Tremor
-                } else if diff <= 50 {
-                    Self::Quake
-                } else if diff <= 200 {
-                    Self::Major
-                } else {
-                    Self::Disaster
-                }
+            (Some(old), Some(new)) => Self::from_delta(old.abs_diff(new), thresholds),
+        }
+    }
+
+    /// Combine the intensities of a bucket of commits into one cell.
+    pub fn calc_bucket(
+        history: &crate::data::History,
+        path: &str,
+        commits: &Range<usize>,
+        aggregate: Aggregate,
+        thresholds: &IntensityThresholds,
+    ) -> Self {
+        let mut peak = Self::None;
+        let mut total = 0usize;
+
+        for i in commits.clone() {
+            let intensity = Self::calc(history, path, i, thresholds);
+            if matches!(intensity, Self::Created | Self::Deleted) {
+                return intensity;
+            }
+            if intensity.severity() > peak.severity() {
+                peak = intensity;
             }
+            total += line_delta(history, path, i)
+                .and_then(|d| usize::try_from(d.unsigned_abs()).ok())
+                .unwrap_or(0);
+        }
+
+        match aggregate {
+            Aggregate::Sum if peak.severity() > Self::Quiet.severity() => {
+                Self::from_delta(total, thresholds)
+            }
+            _ => peak,
+        }
+    }
+
+    /// The more severe of two intensities, used to aggregate directory rows.
+    #[must_use]
+    pub fn strongest(self, other: Self) -> Self {
+        if other.severity() > self.severity() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn from_delta(diff: usize, thresholds: &IntensityThresholds) -> Self {
+        if diff == 0 {
+            Self::Quiet
+        } else if diff <= thresholds.tremor {
+            Self::Tremor
+        } else if diff <= thresholds.quake {
+            Self::Quake
+        } else if diff <= thresholds.major {
+            Self::Major
+        } else {
+            Self::Disaster
         }
     }
+
+    fn severity(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Quiet => 1,
+            Self::Tremor => 2,
+            Self::Quake => 3,
+            Self::Major => 4,
+            Self::Disaster | Self::Created | Self::Deleted => 5,
+        }
+    }
+
+    fn glyph(self, colors: &SeismicColors) -> (char, Color) {
+        match self {
+            Self::None => (' ', colors.quiet),
+            Self::Quiet => ('·', colors.quiet),
+            Self::Tremor => ('░', colors.tremor),
+            Self::Quake => ('▒', colors.quake),
+            Self::Major => ('▓', colors.major),
+            Self::Disaster => ('█', colors.disaster),
+            Self::Created => ('+', colors.created),
+            Self::Deleted => ('╳', colors.deleted),
+        }
+    }
+}
+
+/// Signed line delta for a file at a commit, using the same neighbour as `Intensity::calc`.
+pub fn line_delta(history: &crate::data::History, path: &str, commit_idx: usize) -> Option<i64> {
+    history
+        .files
+        .get(path)?
+        .delta_at(commit_idx, parent_idx(history, commit_idx))
 }
 
-pub fn format_cell(intensity: Intensity, is_current: bool) -> (String, Style) {
-    let (char, bg) = match intensity {
-        Intensity::None => (' ', CLR_QUIET),
-        Intensity::Quiet => ('·', CLR_QUIET),
-        Intensity::Tremor => ('░', CLR_TREMOR),
-        Intensity::Quake => ('▒', CLR_QUAKE),
-        Intensity::Major => ('▓', CLR_MAJOR),
-        Intensity::Disaster => ('█', CLR_DISASTER),
-        Intensity::Created => ('+', CLR_NEW),
-        Intensity::Deleted => ('╳', CLR_DELETED),
+/// The next older loaded commit, which a commit's change is measured against;
+/// indices run newest first.
+fn parent_idx(history: &crate::data::History, commit_idx: usize) -> Option<usize> {
+    Some(commit_idx + 1).filter(|&i| i < history.commits.len())
+}
+
+pub fn format_cell(
+    intensity: Intensity,
+    is_current: bool,
+    width: usize,
+    colors: &SeismicColors,
+) -> (String, Style) {
+    let (char, bg) = intensity.glyph(colors);
+    (format!("{char:<width$}"), cell_style(bg, is_current))
+}
+
+/// Format a wide detail cell showing the numeric line delta.
+pub fn format_delta(
+    intensity: Intensity,
+    delta: Option<i64>,
+    is_current: bool,
+    width: usize,
+    colors: &SeismicColors,
+) -> (String, Style) {
+    let (char, bg) = intensity.glyph(colors);
+    let text = match (intensity, delta) {
+        (Intensity::Created, _) => "new".to_string(),
+        (Intensity::Deleted, _) => "del".to_string(),
+        (Intensity::None | Intensity::Quiet, _) | (_, None) => char.to_string(),
+        (_, Some(d)) if d.abs() >= 1000 => format!("{:+}k", d / 1000),
+        (_, Some(d)) => format!("{d:+}"),
     };
+    (format!("{text:<width$}"), cell_style(bg, is_current))
+}
 
-    let style = if is_current {
+fn cell_style(bg: Color, is_current: bool) -> Style {
+    if is_current {
         Style::default().fg(Color::White).bg(bg)
     } else {
         Style::default().fg(bg)
-    };
-
-    (char.to_string(), style)
+    }
 }
+
+#[cfg(test)]
+mod tests;
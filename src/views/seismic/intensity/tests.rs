@@ -0,0 +1,47 @@
+use super::*;
+use crate::config::IntensityThresholds;
+use crate::error::Result;
+use crate::views::seismic::testing::history;
+
+#[test]
+fn calc_measures_a_commit_against_its_parent() -> Result<()> {
+    // Newest first: the file is created at commit 2, grows at 1 and is deleted at 0.
+    let history = history(&[0; 4], &[("a.rs", &[None, Some(40), Some(10), None])])?;
+    let thresholds = IntensityThresholds::default();
+    let at = |i| Intensity::calc(&history, "a.rs", i, &thresholds);
+    assert_eq!(at(0), Intensity::Deleted);
+    assert_eq!(at(1), Intensity::Quake);
+    assert_eq!(at(2), Intensity::Created);
+    assert_eq!(at(3), Intensity::None);
+    assert_eq!(line_delta(&history, "a.rs", 1), Some(30));
+    Ok(())
+}
+
+#[test]
+fn calc_bucket_takes_the_peak_or_the_summed_change() -> Result<()> {
+    // Three edits of 8 lines each: each a tremor, 24 lines together a quake.
+    let lines = [Some(24), Some(16), Some(8), Some(0)];
+    let history = history(&[0; 4], &[("a.rs", &lines)])?;
+    let thresholds = IntensityThresholds::default();
+    let bucket =
+        |range, aggregate| Intensity::calc_bucket(&history, "a.rs", &range, aggregate, &thresholds);
+    assert_eq!(bucket(0..3, Aggregate::Max), Intensity::Tremor);
+    assert_eq!(bucket(0..3, Aggregate::Sum), Intensity::Quake);
+    // The oldest commit has no parent loaded, so it counts as creating the file.
+    assert_eq!(bucket(2..4, Aggregate::Sum), Intensity::Created);
+    Ok(())
+}
+
+#[test]
+fn calc_bucket_reports_creation_and_deletion_over_any_size() -> Result<()> {
+    let lines = [None, Some(500), Some(1), Some(1)];
+    let history = history(&[0; 4], &[("a.rs", &lines)])?;
+    let thresholds = IntensityThresholds::default();
+    for aggregate in [Aggregate::Max, Aggregate::Sum] {
+        let cell = Intensity::calc_bucket(&history, "a.rs", &(0..3), aggregate, &thresholds);
+        assert_eq!(cell, Intensity::Deleted);
+    }
+    let quiet = Intensity::calc_bucket(&history, "a.rs", &(2..3), Aggregate::Sum, &thresholds);
+    assert_eq!(quiet, Intensity::Quiet);
+    Ok(())
+}
@@ -2,7 +2,10 @@ mod grid;
 mod header;
 mod intensity;
 mod status;
-mod timeline;
+mod zoom;
+
+#[cfg(test)]
+mod testing;
 
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -10,7 +13,10 @@ use ratatui::{
 };
 
 use crate::app::App;
-use crate::views::Render;
+use crate::views::{timeline, Render};
+
+pub use grid::SortMode;
+pub use zoom::{Aggregate, Zoom};
 
 pub fn draw(f: &mut Frame, app: &App) -> Render {
     let mut render = Render::new();
@@ -33,7 +39,7 @@ pub fn draw(f: &mut Frame, app: &App) -> Render {
     );
 
     header::draw_header(f, header_area, app, &mut render);
-    timeline::draw_timeline(f, timeline_area, app);
+    timeline::draw(f, timeline_area, app);
     grid::draw(f, grid_area, app, &mut render);
     status::draw_status(f, status_area, app);
 
@@ -1,6 +1,8 @@
 use ratatui::{layout::Rect, style::Style, widgets::Paragraph, Frame};
 
 use crate::app::App;
+use crate::keymap::Action;
+use crate::views::{hints, prompt};
 
 pub fn draw_status(f: &mut Frame, area: Rect, app: &App) {
     let filter_status = if app.seismic_filter_inactive() {
@@ -8,14 +10,62 @@ pub fn draw_status(f: &mut Frame, area: Rect, app: &App) {
     } else {
         ""
     };
+    let search_status = prompt::search_status(app);
+
+    let pending = hints::pending(app);
+    let undo = hints::undo(app);
+    let selected = hints::selection(app);
+    let zoom = hints::pair(app, Action::ZoomIn, Action::ZoomOut, "zoom");
+    let scroll = hints::pair(app, Action::ScrollDown, Action::ScrollUp, "scroll");
 
     let status = match app.selected_file() {
-        Some(path) => {
-            format!(" {path} │ [R]estore [F]ilter [j/k]scroll [Q]uit{filter_status} ")
-        }
-        None => {
-            format!(" [scroll]time [j/k]files [F]ilter [1-6]views [Q]uit{filter_status} ")
-        }
+        Some(path) => format!(
+            " {path} │ {} {zoom} {scroll} {}{filter_status}{selected}{undo}{search_status}{pending} ",
+            hints::hints(
+                app,
+                &[Action::Restore, Action::OpenExternal, Action::ToggleFilter]
+            ),
+            hints::hints(
+                app,
+                &[
+                    Action::MarkBase,
+                    Action::TogglePlayback,
+                    Action::FindFile,
+                    Action::SearchCommits,
+                    Action::Pickaxe,
+                    Action::CommandPalette,
+                    Action::Help,
+                    Action::Quit
+                ]
+            )
+        ),
+        None => format!(
+            " [scroll]time {scroll} {} {} {zoom} {} {} {}{filter_status}{selected}{undo}{search_status}{pending} ",
+            hints::focus(app),
+            hints::hints(
+                app,
+                &[
+                    Action::ToggleFilter,
+                    Action::CycleSort,
+                    Action::ToggleGrouping
+                ]
+            ),
+            hints::hints(app, &[Action::ToggleAggregate]),
+            hints::views(app),
+            hints::hints(
+                app,
+                &[
+                    Action::MarkBase,
+                    Action::TogglePlayback,
+                    Action::FindFile,
+                    Action::SearchCommits,
+                    Action::Pickaxe,
+                    Action::CommandPalette,
+                    Action::Help,
+                    Action::Quit
+                ]
+            )
+        ),
     };
 
     f.render_widget(
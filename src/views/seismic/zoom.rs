@@ -0,0 +1,143 @@
+use std::ops::Range;
+
+use chrono::{DateTime, Datelike, Local};
+
+use crate::data::History;
+
+/// Horizontal scale of the seismic grid: how many commits share one cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Zoom {
+    /// One commit per wide cell, showing the numeric line delta.
+    Detail,
+    #[default]
+    Commit,
+    Commits(usize),
+    Day,
+    Week,
+    Month,
+}
+
+impl Zoom {
+    /// Zoom levels ordered from closest to widest.
+    pub const ALL: [Zoom; 7] = [
+        Zoom::Detail,
+        Zoom::Commit,
+        Zoom::Commits(5),
+        Zoom::Commits(20),
+        Zoom::Day,
+        Zoom::Week,
+        Zoom::Month,
+    ];
+
+    pub fn name(self) -> String {
+        match self {
+            Zoom::Detail => "detail".to_string(),
+            Zoom::Commit => "1 commit".to_string(),
+            Zoom::Commits(n) => format!("{n} commits"),
+            Zoom::Day => "day".to_string(),
+            Zoom::Week => "week".to_string(),
+            Zoom::Month => "month".to_string(),
+        }
+    }
+
+    pub fn cell_width(self) -> u16 {
+        if self == Zoom::Detail {
+            5
+        } else {
+            2
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|z| *z == self).unwrap_or(1)
+    }
+
+    #[must_use]
+    pub fn zoom_in(self) -> Self {
+        let i = self.index().saturating_sub(1);
+        Self::ALL.get(i).copied().unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn zoom_out(self) -> Self {
+        let i = (self.index() + 1).min(Self::ALL.len() - 1);
+        Self::ALL.get(i).copied().unwrap_or_default()
+    }
+
+    /// Group commit indices into contiguous buckets, one per grid cell.
+    pub fn buckets(self, history: &History) -> Vec<Range<usize>> {
+        let total = history.commits.len();
+        match self {
+            Zoom::Detail | Zoom::Commit => chunked(total, 1),
+            Zoom::Commits(n) => chunked(total, n),
+            Zoom::Day | Zoom::Week | Zoom::Month => self.by_period(history),
+        }
+    }
+
+    fn by_period(self, history: &History) -> Vec<Range<usize>> {
+        let mut buckets: Vec<Range<usize>> = Vec::new();
+        let mut last_key = None;
+
+        for (i, commit) in history.commits.iter().enumerate() {
+            let key = self.period_key(commit.time);
+            match buckets.last_mut() {
+                Some(bucket) if last_key == Some(key) => bucket.end = i + 1,
+                _ => buckets.push(i..i + 1),
+            }
+            last_key = Some(key);
+        }
+
+        buckets
+    }
+
+    fn period_key(self, secs: i64) -> (i32, u32) {
+        let Some(utc) = DateTime::from_timestamp(secs, 0) else {
+            return (0, 0);
+        };
+        let date = utc.with_timezone(&Local).date_naive();
+        match self {
+            Zoom::Week => {
+                let week = date.iso_week();
+                (week.year(), week.week())
+            }
+            Zoom::Month => (date.year(), date.month()),
+            _ => (date.year(), date.ordinal()),
+        }
+    }
+}
+
+fn chunked(total: usize, size: usize) -> Vec<Range<usize>> {
+    let size = size.max(1);
+    (0..total)
+        .step_by(size)
+        .map(|start| start..(start + size).min(total))
+        .collect()
+}
+
+/// How per-commit intensities combine when a cell holds several commits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Aggregate {
+    #[default]
+    Max,
+    Sum,
+}
+
+impl Aggregate {
+    pub fn name(self) -> &'static str {
+        match self {
+            Aggregate::Max => "max",
+            Aggregate::Sum => "sum",
+        }
+    }
+
+    #[must_use]
+    pub fn toggle(self) -> Self {
+        match self {
+            Aggregate::Max => Aggregate::Sum,
+            Aggregate::Sum => Aggregate::Max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
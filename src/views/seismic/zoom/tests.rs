@@ -0,0 +1,67 @@
+use chrono::TimeZone;
+
+use super::*;
+use crate::error::Result;
+use crate::views::seismic::testing::history;
+
+/// Seconds since the epoch of a local time, so period buckets do not depend on
+/// the machine's time zone.
+fn local(month: u32, day: u32, hour: u32) -> i64 {
+    Local
+        .with_ymd_and_hms(2024, month, day, hour, 0, 0)
+        .earliest()
+        .map_or(0, |t| t.timestamp())
+}
+
+#[test]
+fn commit_buckets_hold_one_commit_each() -> Result<()> {
+    let history = history(&[0; 3], &[])?;
+    assert_eq!(Zoom::Commit.buckets(&history), vec![0..1, 1..2, 2..3]);
+    assert_eq!(Zoom::Detail.buckets(&history), vec![0..1, 1..2, 2..3]);
+    Ok(())
+}
+
+#[test]
+fn commits_buckets_end_with_a_partial_bucket() -> Result<()> {
+    let history = history(&[0; 12], &[])?;
+    assert_eq!(
+        Zoom::Commits(5).buckets(&history),
+        vec![0..5, 5..10, 10..12]
+    );
+    assert_eq!(Zoom::Commits(4).buckets(&history), vec![0..4, 4..8, 8..12]);
+    assert_eq!(Zoom::Commits(20).buckets(&history), vec![0..12]);
+    Ok(())
+}
+
+#[test]
+fn no_commits_give_no_buckets() -> Result<()> {
+    let history = history(&[], &[])?;
+    assert!(Zoom::Commits(5).buckets(&history).is_empty());
+    assert!(Zoom::Day.buckets(&history).is_empty());
+    Ok(())
+}
+
+#[test]
+fn period_buckets_group_neighbouring_commits_of_the_same_period() -> Result<()> {
+    // Newest first: two commits on March 5th, one on the 4th, one in February.
+    let times = [
+        local(3, 5, 18),
+        local(3, 5, 9),
+        local(3, 4, 12),
+        local(2, 20, 12),
+    ];
+    let history = history(&times, &[])?;
+    assert_eq!(Zoom::Day.buckets(&history), vec![0..2, 2..3, 3..4]);
+    assert_eq!(Zoom::Month.buckets(&history), vec![0..3, 3..4]);
+    // March 4th and 5th 2024 are a Monday and Tuesday of the same ISO week.
+    assert_eq!(Zoom::Week.buckets(&history), vec![0..3, 3..4]);
+    Ok(())
+}
+
+#[test]
+fn zoom_steps_stop_at_both_ends() {
+    assert_eq!(Zoom::Commit.zoom_in(), Zoom::Detail);
+    assert_eq!(Zoom::Detail.zoom_in(), Zoom::Detail);
+    assert_eq!(Zoom::Commit.zoom_out(), Zoom::Commits(5));
+    assert_eq!(Zoom::Month.zoom_out(), Zoom::Month);
+}
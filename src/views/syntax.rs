@@ -0,0 +1,339 @@
+use ratatui::{
+    style::{Color, Style},
+    text::Span,
+};
+
+/// Enough of a language's lexical rules to color it line by line.
+pub struct Syntax {
+    keywords: &'static [&'static str],
+    line_comment: Option<&'static str>,
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+}
+
+const RUST: Syntax = Syntax {
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+        "true", "type", "unsafe", "use", "where", "while",
+    ],
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    // Single quotes also start lifetimes, so only double-quoted strings are colored.
+    quotes: &['"'],
+};
+
+const PYTHON: Syntax = Syntax {
+    keywords: &[
+        "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del",
+        "elif", "else", "except", "False", "finally", "for", "from", "global", "if", "import",
+        "in", "is", "lambda", "None", "nonlocal", "not", "or", "pass", "raise", "return", "True",
+        "try", "while", "with", "yield",
+    ],
+    line_comment: Some("#"),
+    block_comment: None,
+    quotes: &['"', '\''],
+};
+
+const JAVASCRIPT: Syntax = Syntax {
+    keywords: &[
+        "async",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "export",
+        "extends",
+        "false",
+        "finally",
+        "for",
+        "from",
+        "function",
+        "if",
+        "import",
+        "in",
+        "instanceof",
+        "interface",
+        "let",
+        "new",
+        "null",
+        "return",
+        "static",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "type",
+        "typeof",
+        "undefined",
+        "var",
+        "void",
+        "while",
+        "yield",
+    ],
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\'', '`'],
+};
+
+const GO: Syntax = Syntax {
+    keywords: &[
+        "break",
+        "case",
+        "chan",
+        "const",
+        "continue",
+        "default",
+        "defer",
+        "else",
+        "fallthrough",
+        "false",
+        "for",
+        "func",
+        "go",
+        "goto",
+        "if",
+        "import",
+        "interface",
+        "map",
+        "nil",
+        "package",
+        "range",
+        "return",
+        "select",
+        "struct",
+        "switch",
+        "true",
+        "type",
+        "var",
+    ],
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\'', '`'],
+};
+
+const C_FAMILY: Syntax = Syntax {
+    keywords: &[
+        "auto",
+        "bool",
+        "break",
+        "case",
+        "catch",
+        "char",
+        "class",
+        "const",
+        "continue",
+        "default",
+        "delete",
+        "do",
+        "double",
+        "else",
+        "enum",
+        "extends",
+        "extern",
+        "false",
+        "final",
+        "float",
+        "for",
+        "if",
+        "implements",
+        "import",
+        "include",
+        "int",
+        "long",
+        "namespace",
+        "new",
+        "null",
+        "nullptr",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "return",
+        "short",
+        "signed",
+        "sizeof",
+        "static",
+        "struct",
+        "switch",
+        "template",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typedef",
+        "union",
+        "unsigned",
+        "using",
+        "virtual",
+        "void",
+        "volatile",
+        "while",
+    ],
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\''],
+};
+
+const SHELL: Syntax = Syntax {
+    keywords: &[
+        "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if",
+        "in", "local", "return", "then", "until", "while",
+    ],
+    line_comment: Some("#"),
+    block_comment: None,
+    quotes: &['"', '\''],
+};
+
+const CONFIG: Syntax = Syntax {
+    keywords: &["true", "false", "null"],
+    line_comment: Some("#"),
+    block_comment: None,
+    quotes: &['"', '\''],
+};
+
+const JSON: Syntax = Syntax {
+    keywords: &["true", "false", "null"],
+    line_comment: None,
+    block_comment: None,
+    quotes: &['"'],
+};
+
+impl Syntax {
+    /// Rules for a file, picked by extension; `None` for plain text.
+    pub fn for_path(path: &str) -> Option<&'static Syntax> {
+        let ext = path.rsplit_once('.').map(|(_, ext)| ext.to_lowercase())?;
+        let syntax = match ext.as_str() {
+            "rs" => &RUST,
+            "py" | "pyi" => &PYTHON,
+            "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" => &JAVASCRIPT,
+            "go" => &GO,
+            "c" | "h" | "cc" | "cpp" | "cxx" | "hpp" | "java" | "cs" | "kt" | "swift" => &C_FAMILY,
+            "sh" | "bash" | "zsh" => &SHELL,
+            "toml" | "yaml" | "yml" | "ini" | "cfg" => &CONFIG,
+            "json" => &JSON,
+            _ => return None,
+        };
+        Some(syntax)
+    }
+}
+
+/// Colors lines in order, carrying block comments from one line to the next.
+pub struct Highlighter {
+    syntax: Option<&'static Syntax>,
+    in_block_comment: bool,
+}
+
+impl Highlighter {
+    pub fn new(path: &str) -> Self {
+        Self {
+            syntax: Syntax::for_path(path),
+            in_block_comment: false,
+        }
+    }
+
+    /// Spans for the next line of the file.
+    pub fn line(&mut self, text: &str) -> Vec<Span<'static>> {
+        let Some(syntax) = self.syntax else {
+            return vec![Span::styled(text.to_string(), style::TEXT)];
+        };
+        let mut spans = Vec::new();
+        let mut rest = text;
+        while !rest.is_empty() {
+            let (len, token_style) = self.token(syntax, rest);
+            let (token, tail) = rest
+                .split_at_checked(len)
+                .filter(|(token, _)| !token.is_empty())
+                .unwrap_or((rest, ""));
+            spans.push(Span::styled(token.to_string(), token_style));
+            rest = tail;
+        }
+        spans
+    }
+
+    /// Byte length and style of the token at the start of `rest`.
+    fn token(&mut self, syntax: &Syntax, rest: &str) -> (usize, Style) {
+        if let Some((open, close)) = syntax.block_comment {
+            if self.in_block_comment || rest.starts_with(open) {
+                let from = if self.in_block_comment { 0 } else { open.len() };
+                self.in_block_comment = true;
+                return match rest.get(from..).and_then(|r| r.find(close)) {
+                    Some(end) => {
+                        self.in_block_comment = false;
+                        (from + end + close.len(), style::COMMENT)
+                    }
+                    None => (rest.len(), style::COMMENT),
+                };
+            }
+        }
+        if syntax.line_comment.is_some_and(|c| rest.starts_with(c)) {
+            return (rest.len(), style::COMMENT);
+        }
+
+        let Some(first) = rest.chars().next() else {
+            return (0, style::TEXT);
+        };
+        if syntax.quotes.contains(&first) {
+            return (string_len(rest, first), style::STRING);
+        }
+        if first.is_ascii_digit() {
+            return (word_len(rest, true), style::NUMBER);
+        }
+        if first.is_alphabetic() || first == '_' {
+            let len = word_len(rest, false);
+            let word = rest.get(..len).unwrap_or_default();
+            let token_style = if syntax.keywords.contains(&word) {
+                style::KEYWORD
+            } else if first.is_uppercase() {
+                style::TYPE
+            } else {
+                style::TEXT
+            };
+            return (len, token_style);
+        }
+        (first.len_utf8(), style::TEXT)
+    }
+}
+
+/// Length of a word of letters, digits and underscores, plus dots for numbers.
+fn word_len(text: &str, dots: bool) -> usize {
+    text.char_indices()
+        .find(|&(_, c)| !(c.is_alphanumeric() || c == '_' || (dots && c == '.')))
+        .map_or(text.len(), |(i, _)| i)
+        .max(1)
+}
+
+/// Length of a string literal up to its unescaped closing quote, or the line end.
+fn string_len(text: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == quote => return i + c.len_utf8(),
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+mod style {
+    use super::{Color, Style};
+
+    pub const TEXT: Style = Style::new().fg(Color::Gray);
+    pub const KEYWORD: Style = Style::new().fg(Color::Magenta);
+    pub const TYPE: Style = Style::new().fg(Color::Yellow);
+    pub const STRING: Style = Style::new().fg(Color::Green);
+    pub const NUMBER: Style = Style::new().fg(Color::Cyan);
+    pub const COMMENT: Style = Style::new().fg(Color::DarkGray);
+}
@@ -35,7 +35,11 @@ pub fn draw(f: &mut Frame, area: Rect, app: &App, render: &mut crate::views::Ren
     for (i, mode) in ViewMode::ALL.iter().enumerate() {
         #[allow(clippy::cast_possible_truncation)]
         let x = tabs_area.x + (i as u16 * tab_width);
-        let rect = Rect::new(x, tabs_area.y, tab_width, tabs_area.height);
+        // Tabs that do not fit a narrow terminal are left out.
+        let rect = Rect::new(x, tabs_area.y, tab_width, tabs_area.height).intersection(tabs_area);
+        if rect.is_empty() {
+            break;
+        }
 
         let is_active = app.view() == *mode;
         let is_hover = app.mouse().hover == HitTarget::ViewTab(i);
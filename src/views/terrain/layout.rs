@@ -1,5 +1,8 @@
 use ratatui::layout::Rect;
 
+/// Maximum number of files laid out in the treemap.
+pub const MAX_FILES: usize = 24;
+
 /// Compute treemap layout for files in a given area.
 #[allow(
     clippy::cast_precision_loss,
@@ -14,9 +17,8 @@ pub fn compute(files: &[(&str, usize)], area: Rect) -> Vec<(String, usize, Rect)
 
     let mut result = Vec::new();
     let mut remaining = area;
-    let max_files = 24;
 
-    for (path, lines) in files.iter().take(max_files) {
+    for (path, lines) in files.iter().take(MAX_FILES) {
         if remaining.width < 6 || remaining.height < 2 {
             break;
         }
@@ -2,7 +2,6 @@ mod header;
 mod layout;
 mod render;
 mod status;
-mod timeline;
 
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -10,7 +9,7 @@ use ratatui::{
 };
 
 use crate::app::App;
-use crate::views::Render;
+use crate::views::{timeline, Render};
 
 pub fn draw(f: &mut Frame, app: &App) -> Render {
     let mut render = Render::new();
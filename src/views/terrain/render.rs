@@ -2,76 +2,113 @@ use ratatui::{
     layout::Rect,
     style::{Color, Style},
     text::Line,
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, BorderType, Borders, Paragraph},
     Frame,
 };
 
 use crate::app::App;
+use crate::config::TerrainColors;
 use crate::data::Health;
 use crate::mouse::{HitBox, HitTarget};
 use crate::views::Render;
 
 use super::layout;
 
-const CLR_STABLE: Color = Color::Rgb(46, 49, 55);
-const CLR_GREW: Color = Color::Rgb(80, 180, 120);
-const CLR_SHRANK: Color = Color::Rgb(180, 160, 80);
-const CLR_TRAUMA: Color = Color::Rgb(200, 60, 60);
-const CLR_NEW: Color = Color::Rgb(80, 140, 200);
-const CLR_HOVER: Color = Color::Rgb(255, 100, 255);
-const CLR_SELECTED: Color = Color::Rgb(0, 220, 220);
-
 pub fn draw(f: &mut Frame, area: Rect, app: &App, render: &mut Render) {
-    let title = format!(" TERRAIN @ {} ", app.commit_label());
+    let title = match app.path_filter() {
+        Some(dir) => format!(" TERRAIN {dir} @ {} ", app.commit_label()),
+        None => format!(" TERRAIN @ {} ", app.commit_label()),
+    };
 
     let block = Block::default().borders(Borders::ALL).title(title);
     let inner = block.inner(area);
     f.render_widget(block, area);
 
-    let files = app.files_at_current();
+    let mut files = app.files_at_current();
     if files.is_empty() {
         return;
     }
-
-    let layout_data = layout::compute(&files, inner);
+    let layout_data = layout_with_selected(&mut files, app.selected_file(), inner);
+    let colors = &app.config().terrain_colors;
 
     for (path, lines, rect) in layout_data {
         let health = app.file_health(&path);
-        let is_hover = app.mouse().hover == HitTarget::File(path.clone());
-        let is_selected = app.selected_file() == Some(&path);
+        let hit_box = HitBox::new(rect, HitTarget::File(path.clone()));
+        let is_hover = app.is_emphasized(&hit_box);
+        let is_selected = app.selected_file() == Some(&path) || app.is_path_selected(&path);
 
-        let bg = if is_selected {
-            CLR_SELECTED
+        let (bg, border) = if is_selected {
+            (colors.selected, BorderType::Double)
         } else if is_hover {
-            CLR_HOVER
+            (colors.hover, BorderType::Thick)
+        } else {
+            (health_color(health, colors), BorderType::Plain)
+        };
+        let fg = if bg == colors.stable {
+            Color::Gray
         } else {
-            health_color(health)
+            Color::Black
         };
 
-        let display_name = truncate_path(&path, rect.width as usize);
+        let display_name = truncate_path(&path, (rect.width as usize).saturating_sub(4));
         let content = vec![
-            Line::styled(display_name, Style::default().fg(Color::Black)),
-            Line::styled(format!("{lines}"), Style::default().fg(Color::Black)),
+            Line::styled(
+                format!("{} {display_name}", health_glyph(health)),
+                Style::default().fg(fg),
+            ),
+            Line::styled(format!("{lines}"), Style::default().fg(fg)),
         ];
 
         let para = Paragraph::new(content)
             .style(Style::default().bg(bg))
-            .block(Block::default().borders(Borders::ALL));
+            .block(Block::default().borders(Borders::ALL).border_type(border));
 
         f.render_widget(para, rect);
-        render
-            .hit_boxes
-            .push(HitBox::new(rect, HitTarget::File(path)));
+        render.hit_boxes.push(hit_box);
+    }
+}
+
+/// Lay out the treemap, moving the selected file to the front when it is too
+/// small to rank or would be squeezed out of a narrow area.
+fn layout_with_selected(
+    files: &mut Vec<(&str, usize)>,
+    selected: Option<&str>,
+    area: Rect,
+) -> Vec<(String, usize, Rect)> {
+    let laid_out = layout::compute(files, area);
+    if laid_out
+        .iter()
+        .any(|(path, _, _)| Some(path.as_str()) == selected)
+    {
+        return laid_out;
+    }
+    let Some(pos) = files.iter().position(|(p, _)| Some(*p) == selected) else {
+        return laid_out;
+    };
+    let file = files.remove(pos);
+    files.insert(0, file);
+    layout::compute(files, area)
+}
+
+fn health_color(health: Health, colors: &TerrainColors) -> Color {
+    match health {
+        Health::Stable | Health::Deleted => colors.stable,
+        Health::Grew => colors.grew,
+        Health::Shrank => colors.shrank,
+        Health::Trauma => colors.trauma,
+        Health::New => colors.new,
     }
 }
 
-fn health_color(health: Health) -> Color {
+/// Shape marker for each health class, so it never relies on color alone.
+fn health_glyph(health: Health) -> char {
     match health {
-        Health::Stable | Health::Deleted => CLR_STABLE,
-        Health::Grew => CLR_GREW,
-        Health::Shrank => CLR_SHRANK,
-        Health::Trauma => CLR_TRAUMA,
-        Health::New => CLR_NEW,
+        Health::Stable => '=',
+        Health::Grew => '▲',
+        Health::Shrank => '▼',
+        Health::Trauma => '✖',
+        Health::New => '+',
+        Health::Deleted => '╳',
     }
 }
 
@@ -1,11 +1,49 @@
 use ratatui::{layout::Rect, style::Style, widgets::Paragraph, Frame};
 
 use crate::app::App;
+use crate::keymap::Action;
+use crate::views::{hints, prompt};
 
 pub fn draw(f: &mut Frame, area: Rect, app: &App) {
+    let search_status = prompt::search_status(app);
+    let pending = hints::pending(app);
+    let undo = hints::undo(app);
+    let selected = hints::selection(app);
     let status = match app.selected_file() {
-        Some(path) => format!(" {path} │ [R]estore [Esc]clear [Q]uit "),
-        None => " [click]select [scroll]time [1-6]views [Q]uit ".to_string(),
+        Some(path) => format!(
+            " {path} │ {} {}{selected}{undo}{search_status}{pending} ",
+            hints::pair(app, Action::EnterDir, Action::LeaveDir, "dir"),
+            hints::hints(
+                app,
+                &[
+                    Action::Restore,
+                    Action::OpenExternal,
+                    Action::Pickaxe,
+                    Action::ClearSelection,
+                    Action::FindFile,
+                    Action::Quit
+                ]
+            )
+        ),
+        None => format!(
+            " [click]select {} [scroll]time {} {} {}{selected}{undo}{search_status}{pending} ",
+            hints::focus(app),
+            hints::pair(app, Action::NextCommit, Action::PrevCommit, "time"),
+            hints::views(app),
+            hints::hints(
+                app,
+                &[
+                    Action::MarkBase,
+                    Action::TogglePlayback,
+                    Action::FindFile,
+                    Action::SearchCommits,
+                    Action::Pickaxe,
+                    Action::CommandPalette,
+                    Action::Help,
+                    Action::Quit
+                ]
+            )
+        ),
     };
 
     f.render_widget(
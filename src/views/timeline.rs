@@ -0,0 +1,113 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::data::Commit;
+use crate::views::decorations::{label_refs, ref_tick};
+
+/// Timeline cell with nothing on it.
+const BARE: &str = "─";
+
+pub fn draw(f: &mut Frame, area: Rect, app: &App) {
+    let total = app.commit_count();
+    let current = app.commit_idx();
+
+    let title = match app.current_commit() {
+        Some(c) if !c.refs.is_empty() => format!(
+            " {} / {} │ ({}) {} ",
+            current + 1,
+            total,
+            c.ref_names(),
+            truncate_text(&c.summary, 50)
+        ),
+        Some(c) => format!(
+            " {} / {} │ {} ",
+            current + 1,
+            total,
+            truncate_text(&c.summary, 50)
+        ),
+        None => " Timeline ".to_string(),
+    };
+
+    let block = Block::default().borders(Borders::ALL).title(title);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if total == 0 || inner.width == 0 {
+        return;
+    }
+
+    let width = inner.width as usize;
+    let marker_pos = column_of(current, total, width);
+    let base_pos = app
+        .compare_base_idx()
+        .map(|base| column_of(base, total, width));
+
+    let marks: Vec<(usize, char)> = app
+        .marked_commits()
+        .into_iter()
+        .map(|(idx, letter)| (column_of(idx, total, width), letter))
+        .collect();
+
+    let matches: Vec<usize> = app
+        .search_matches()
+        .iter()
+        .map(|&i| column_of(i, total, width))
+        .collect();
+
+    let decorated: Vec<(usize, &Commit)> = app
+        .history()
+        .commits
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| !c.refs.is_empty())
+        .map(|(i, c)| (column_of(i, total, width), c))
+        .collect();
+
+    let mut line: Vec<Span> = (0..width)
+        .map(|i| {
+            if i == marker_pos {
+                Span::styled("◉", Style::default().fg(Color::Cyan))
+            } else if base_pos == Some(i) {
+                Span::styled("◆", Style::default().fg(Color::Magenta))
+            } else if let Some((_, letter)) = marks.iter().find(|(col, _)| *col == i) {
+                Span::styled(letter.to_string(), Style::default().fg(Color::Green))
+            } else if matches.contains(&i) {
+                Span::styled("┃", Style::default().fg(Color::Yellow))
+            } else if let Some(tick) = decorated
+                .iter()
+                .find(|(col, _)| *col == i)
+                .and_then(|(_, c)| ref_tick(c))
+            {
+                tick
+            } else {
+                Span::styled(BARE, Style::default().fg(Color::Cyan))
+            }
+        })
+        .collect();
+    let labels: Vec<(usize, &Commit)> = decorated.iter().map(|&(col, c)| (col + 1, c)).collect();
+    label_refs(&mut line, &labels, BARE, |_| true);
+
+    f.render_widget(Paragraph::new(Line::from(line)), inner);
+}
+
+fn column_of(commit_idx: usize, total: usize, width: usize) -> usize {
+    if total <= 1 {
+        width / 2
+    } else {
+        (commit_idx * width.saturating_sub(1)) / total.saturating_sub(1)
+    }
+}
+
+fn truncate_text(text: &str, max: usize) -> String {
+    if text.len() <= max {
+        text.to_string()
+    } else {
+        format!("{}...", &text[..max.saturating_sub(3)])
+    }
+}
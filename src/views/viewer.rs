@@ -0,0 +1,148 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, Viewer};
+use crate::git_ops::{FileContent, LineRange};
+
+use super::syntax::Highlighter;
+
+/// Background of the line under the cursor.
+const CURSOR_BG: Color = Color::Rgb(50, 50, 50);
+/// Background of lines picked for tracing.
+const SELECTION_BG: Color = Color::Rgb(40, 40, 90);
+
+/// Draw the selected file as of the current commit over the whole screen, with line
+/// numbers and the lines that commit changed marked in the gutter. Returns how many
+/// lines fit.
+pub fn draw(f: &mut Frame, app: &App, viewer: &Viewer) -> usize {
+    let area = f.area();
+    f.render_widget(Clear, area);
+
+    let Some(loaded) = &viewer.loaded else {
+        return 0;
+    };
+    let trace = app
+        .line_trace()
+        .filter(|t| t.path == loaded.path)
+        .map(|t| (t.label.as_str(), t.range_in(&loaded.path, app.commit_idx())));
+    let summary = match (&loaded.content, trace) {
+        (_, Some((label, Some((start, end))))) => format!(" │ tracing {label}, now {start}-{end}"),
+        (_, Some((label, None))) => format!(" │ tracing {label}, not in this commit"),
+        (Some(content), None) if content.binary.is_none() => format!(
+            " │ {} lines, {} changed",
+            content.lines.len(),
+            content.added.len()
+        ),
+        _ => String::new(),
+    };
+    let keys = if viewer.anchor.is_some() {
+        " ↑/↓ extend │ Enter trace │ Esc cancel "
+    } else {
+        " ←/→ commit │ ↑/↓ move │ v select │ Enter trace line │ Esc closes "
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " {} @ {}{summary} ",
+            loaded.path,
+            app.commit_label()
+        ))
+        .title_bottom(keys);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let short_oid = app.current_commit().map_or("", |c| c.short_oid.as_str());
+    let lines = match &loaded.content {
+        None => vec![note(&format!(
+            "{} does not exist at {short_oid}",
+            loaded.path
+        ))],
+        Some(FileContent {
+            binary: Some(size), ..
+        }) => vec![note(&format!("Binary file, {size} bytes"))],
+        Some(content) => {
+            let traced = trace.and_then(|(_, range)| range);
+            content_lines(&loaded.path, content, viewer, traced, inner.height)
+        }
+    };
+    f.render_widget(Paragraph::new(lines), inner);
+    inner.height as usize
+}
+
+/// The visible lines, highlighted from the top so block comments carry over. Lines
+/// removed from a traced range are shown inline, the way `git log -L` prints them.
+fn content_lines(
+    path: &str,
+    content: &FileContent,
+    viewer: &Viewer,
+    traced: Option<LineRange>,
+    height: u16,
+) -> Vec<Line<'static>> {
+    let visible = height as usize;
+    let scroll = viewer
+        .scroll
+        .min(content.lines.len().saturating_sub(visible));
+    let number_width = content.lines.len().to_string().len();
+    let in_trace =
+        |i: usize| traced.is_some_and(|(start, end)| (start.saturating_sub(1)..end).contains(&i));
+    let selection = viewer.anchor.map(|_| viewer.selection());
+
+    let mut highlighter = Highlighter::new(path);
+    let mut lines = Vec::with_capacity(visible);
+    for (i, text) in content.lines.iter().enumerate().take(scroll + visible) {
+        let spans = highlighter.line(text);
+        if i < scroll {
+            continue;
+        }
+        if in_trace(i) {
+            for removed in content.removed.get(&i).into_iter().flatten() {
+                lines.push(Line::styled(
+                    format!("{:number_width$} - {removed}", ""),
+                    Style::default().fg(Color::Red),
+                ));
+            }
+        }
+
+        let marker = if content.added.contains(&i) {
+            Span::styled("+", Style::default().fg(Color::Green))
+        } else if content.removed.contains_key(&i) {
+            Span::styled("-", Style::default().fg(Color::Red))
+        } else {
+            Span::raw(" ")
+        };
+        let number_style = if in_trace(i) {
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let mut line = vec![
+            Span::styled(format!("{:>number_width$} ", i + 1), number_style),
+            marker,
+            Span::raw(" "),
+        ];
+        line.extend(spans);
+
+        let selected =
+            selection.is_some_and(|(start, end)| (start.saturating_sub(1)..end).contains(&i));
+        let line = match (selected, i == viewer.cursor) {
+            (true, _) => Line::from(line).style(Style::default().bg(SELECTION_BG)),
+            (false, true) => Line::from(line).style(Style::default().bg(CURSOR_BG)),
+            (false, false) => Line::from(line),
+        };
+        lines.push(line);
+        if lines.len() >= visible {
+            break;
+        }
+    }
+    lines
+}
+
+fn note(text: &str) -> Line<'static> {
+    Line::styled(format!(" {text}"), Style::default().fg(Color::DarkGray))
+}
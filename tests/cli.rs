@@ -0,0 +1,31 @@
+use std::error::Error;
+use std::path::Path;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use git2::{Repository, Signature};
+use predicates::prelude::*;
+
+/// A repository with one commit and `config` as its `.git-trek.toml`.
+fn repo_with_config(dir: &Path, config: &str) -> Result<(), Box<dyn Error>> {
+    let repo = Repository::init(dir)?;
+    let signature = Signature::now("Test", "test@example.com")?;
+    let tree = repo.find_tree(repo.index()?.write_tree()?)?;
+    repo.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])?;
+    std::fs::write(dir.join(".git-trek.toml"), config)?;
+    Ok(())
+}
+
+#[test]
+fn bad_repo_config_is_reported_before_the_ui_starts() -> Result<(), Box<dyn Error>> {
+    let dir = tempfile::tempdir()?;
+    repo_with_config(dir.path(), "[health]\ntrauma = 2.0\n")?;
+
+    cargo_bin_cmd!("git-trek")
+        .current_dir(dir.path())
+        .env("XDG_CONFIG_HOME", dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("health thresholds must satisfy"))
+        .stderr(predicate::str::contains("\u{1b}[").not());
+    Ok(())
+}